@@ -0,0 +1,73 @@
+use crate::StabilizerCHForm;
+use crate::api::qargs::check_qargs;
+use crate::api::representation::MixedStabilizerState;
+use crate::api::representation::mixed_stabilizer_state::MAX_DENSITY_MATRIX_QUBITS;
+use ndarray::Array2;
+use num_complex::Complex64;
+
+impl StabilizerCHForm {
+    /// Returns the dense density matrix |ψ><ψ|, with qubit 0 as the least
+    /// significant bit as in `to_statevector`.
+    ///
+    /// # Returns
+    /// An error if the state has more than 10 qubits.
+    pub fn to_density_matrix(&self) -> Result<Array2<Complex64>, String> {
+        let n = self.n_qubits();
+        if n > MAX_DENSITY_MATRIX_QUBITS {
+            return Err(format!(
+                "to_density_matrix supports at most {} qubits, got {}.",
+                MAX_DENSITY_MATRIX_QUBITS, n
+            ));
+        }
+        let index = |bits: &ndarray::Array1<bool>| -> usize {
+            (0..n).filter(|&j| bits[j]).map(|j| 1 << j).sum()
+        };
+        let support = self.to_sparse_statevector()?;
+        let mut rho = Array2::zeros((1 << n, 1 << n));
+        for (row_bits, row_amplitude) in &support {
+            for (col_bits, col_amplitude) in &support {
+                rho[[index(row_bits), index(col_bits)]] = row_amplitude * col_amplitude.conj();
+            }
+        }
+        Ok(rho)
+    }
+
+    /// Returns the state as a `MixedStabilizerState` with the n generators of
+    /// `stabilizers`. The global phase is lost.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn to_mixed_state(&self) -> MixedStabilizerState {
+        MixedStabilizerState::new(self.n_qubits(), self.stabilizers())
+            .expect("Stabilizers of a pure state are independent and commute.")
+    }
+
+    /// Returns the reduced state on `keep`, tracing out the other qubits.
+    ///
+    /// Qubit `i` of the result is qubit `keep[i]` of `self`. The result is exact;
+    /// call `to_density_matrix` on it to densify.
+    ///
+    /// # Returns
+    /// An error if `keep` contains repeated or out-of-range qubits.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn reduced_density_matrix(&self, keep: &[usize]) -> Result<MixedStabilizerState, String> {
+        self.to_mixed_state().partial_trace(keep)
+    }
+
+    /// Traces out the qubits `qargs`, which may be entangled with the rest, and
+    /// returns the exact mixed state of the remaining qubits in increasing order.
+    ///
+    /// Equivalent to `reduced_density_matrix` on the complement of `qargs`.
+    ///
+    /// # Returns
+    /// An error if `qargs` contains repeated or out-of-range qubits.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn partial_trace(&self, qargs: &[usize]) -> Result<MixedStabilizerState, String> {
+        check_qargs(qargs, self.n_qubits())?;
+        let keep: Vec<usize> = (0..self.n_qubits())
+            .filter(|q| !qargs.contains(q))
+            .collect();
+        self.reduced_density_matrix(&keep)
+    }
+}
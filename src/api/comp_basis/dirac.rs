@@ -0,0 +1,113 @@
+use crate::StabilizerCHForm;
+use std::fmt;
+
+/// The Dirac form is only included in `Display` for supports of at most
+/// 2^MAX_DISPLAYED_SUPPORT_LOG2 basis states.
+const MAX_DISPLAYED_SUPPORT_LOG2: usize = 4;
+
+impl StabilizerCHForm {
+    /// Formats the state as a sum over its non-zero amplitudes, e.g.
+    /// `(|00> + i|11>)/√2`.
+    ///
+    /// Kets are little-endian like the indices of `to_statevector`: qubit 0 is the
+    /// rightmost bit, so `|01>` has qubit 0 in state 1. `CHFormInternalState`
+    /// labels its statevector the same way. Terms are ordered by basis index.
+    ///
+    /// Phases are exact multiples of π/4; a global phase set with
+    /// `set_global_phase` other than 1 is written as a complex prefactor.
+    ///
+    /// NOTE: The support has 2^k elements for some k ≤ n, so the string can be
+    /// exponentially long.
+    pub fn to_dirac_string(&self) -> String {
+        let mut terms: Vec<(String, u8, usize)> = self
+            ._nonzero_amplitudes()
+            .into_iter()
+            .map(|(bits, amplitude)| {
+                let ket = bits
+                    .iter()
+                    .rev()
+                    .map(|&b| if b { '1' } else { '0' })
+                    .collect();
+                let (phase, r) = amplitude
+                    .to_parts()
+                    .expect("Amplitudes on the support are non-zero.");
+                (ket, phase, r)
+            })
+            .collect();
+        // Kets have equal length, so sorting them sorts by basis index.
+        terms.sort();
+
+        let (_, leading, r) = terms[0];
+        let body: Vec<String> = terms
+            .iter()
+            .enumerate()
+            .map(|(i, (ket, phase, _))| {
+                let (negative, magnitude) = phase_parts(phase.wrapping_sub(leading));
+                match (i, negative) {
+                    (0, _) => format!("{}|{}>", magnitude, ket),
+                    (_, false) => format!(" + {}|{}>", magnitude, ket),
+                    (_, true) => format!(" - {}|{}>", magnitude, ket),
+                }
+            })
+            .collect();
+
+        let mut result = String::new();
+        let omega = self.global_phase();
+        if (omega.re - 1.0).abs() > 1e-12 || omega.im.abs() > 1e-12 {
+            result.push_str(&format!("({:.4}{:+.4}i)", omega.re, omega.im));
+        }
+        let (negative, magnitude) = phase_parts(leading);
+        if negative {
+            result.push('-');
+        }
+        result.push_str(magnitude);
+        if terms.len() > 1 {
+            result.push_str(&format!("({})", body.concat()));
+        } else {
+            result.push_str(&body.concat());
+        }
+        result.push_str(&normalization(r));
+        result
+    }
+}
+
+/// Splits e^{ikπ/4} into a sign and one of `""`, `e^{iπ/4}`, `i`, `e^{3iπ/4}`.
+fn phase_parts(k: u8) -> (bool, &'static str) {
+    let magnitude = match k % 4 {
+        0 => "",
+        1 => "e^{iπ/4}",
+        2 => "i",
+        _ => "e^{3iπ/4}",
+    };
+    (k % 8 >= 4, magnitude)
+}
+
+/// Returns the suffix dividing by 2^{r/2}, e.g. `/√2`, `/2` or `/(2√2)`.
+fn normalization(r: usize) -> String {
+    match (r / 2, r % 2) {
+        (0, 0) => String::new(),
+        (0, _) => "/√2".to_string(),
+        (a, 0) => format!("/{}", 1u64 << a),
+        (a, _) => format!("/({}√2)", 1u64 << a),
+    }
+}
+
+/// Formats the state as its stabilizer generators and, if the state is a
+/// superposition of at most 16 basis states, in Dirac notation:
+///
+/// ```text
+/// stabilizers: +XX, +ZZ
+/// state: (|00> + |11>)/√2
+/// ```
+impl fmt::Display for StabilizerCHForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stabilizers: Vec<String> = self.stabilizers().iter().map(|p| p.to_string()).collect();
+        writeln!(f, "stabilizers: {}", stabilizers.join(", "))?;
+        let support_log2 = self.vec_v.iter().filter(|&&v| v).count();
+        if support_log2 <= MAX_DISPLAYED_SUPPORT_LOG2 {
+            write!(f, "state: {}", self.to_dirac_string())
+        } else {
+            write!(f, "state: superposition of 2^{} basis states", support_log2)
+        }
+    }
+}
@@ -1 +1,3 @@
+pub mod density_matrix;
+pub mod dirac;
 pub mod to_statevector;
@@ -1,23 +1,50 @@
 use crate::StabilizerCHForm;
+use ndarray::Array1;
 use num_complex::Complex64;
 
+/// Largest support, as a power of two, accepted by `to_sparse_statevector`.
+const MAX_SPARSE_SUPPORT_LOG2: usize = 24;
+
 impl StabilizerCHForm {
     /// Represents this state as a statevector.
     ///
-    /// NOTE: This implementation iterates over all 2^n basis states. This functionality is mainly for testing and debugging purposes.
-    pub fn to_statevector(&self) -> ndarray::Array1<Complex64> {
+    /// The vector is filled from the non-zero amplitudes (see `to_sparse_statevector`),
+    /// but still has 2^n entries. This functionality is mainly for testing and debugging purposes.
+    pub fn to_statevector(&self) -> Array1<Complex64> {
         let dim = 1 << self.n_qubits(); // 2^n
-        let mut statevector = ndarray::Array1::from_elem(dim, Complex64::new(0.0, 0.0));
-
-        for i in 0..dim {
-            let bitstring: ndarray::Array1<bool> =
-                (0..self.n_qubits()).map(|j| (i & (1 << j)) != 0).collect();
-            statevector[i] = self
-                ._amplitude_at_computational_basis(&bitstring)
-                .to_complex()
-                * self.global_phase();
+        let mut statevector = Array1::from_elem(dim, Complex64::new(0.0, 0.0));
+        for (bitstring, amplitude) in self._nonzero_amplitudes() {
+            let index: usize = (0..self.n_qubits())
+                .filter(|&j| bitstring[j])
+                .map(|j| 1 << j)
+                .sum();
+            statevector[index] = amplitude.to_complex() * self.global_phase();
         }
-
         statevector
     }
+
+    /// Returns the basis states with non-zero amplitude together with their amplitudes.
+    ///
+    /// A stabilizer state is an equal-weight superposition of 2^k basis states for
+    /// some k ≤ n, so e.g. a GHZ state on any number of qubits has two entries.
+    /// Entries are ordered by statevector index, where bit `j` of the bitstring is qubit `j`.
+    ///
+    /// # Returns
+    /// An error if the support has more than 2^24 elements.
+    ///
+    /// Time complexity: O(2^k n^2)
+    pub fn to_sparse_statevector(&self) -> Result<Vec<(Array1<bool>, Complex64)>, String> {
+        let support_log2 = self.vec_v.iter().filter(|&&v| v).count();
+        if support_log2 > MAX_SPARSE_SUPPORT_LOG2 {
+            return Err(format!(
+                "Support of 2^{} basis states exceeds the limit of 2^{}.",
+                support_log2, MAX_SPARSE_SUPPORT_LOG2
+            ));
+        }
+        Ok(self
+            ._nonzero_amplitudes()
+            .into_iter()
+            .map(|(bitstring, amplitude)| (bitstring, amplitude.to_complex() * self.global_phase()))
+            .collect())
+    }
 }
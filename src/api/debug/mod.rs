@@ -7,6 +7,7 @@
 use crate::stabilizer_ch_form::StabilizerCHForm;
 use ndarray::{Array1, Array2};
 use num_complex::Complex64;
+use std::fmt;
 
 /// A snapshot of the internal boolean matrices and vectors of a `StabilizerCHForm`.
 ///
@@ -27,51 +28,57 @@ pub struct CHFormInternalState {
 impl CHFormInternalState {
     /// Prints the internal state to the console in a human-readable format (0s and 1s).
     pub fn pretty_print(&self) {
-        println!("--- CHFormInternalState (n={}) ---", self.n_qubits);
-
-        let print_mat = |name: &str, mat: &Array2<bool>| {
-            println!("{}: [", name);
-            for row in mat.rows() {
-                let s: String = row.iter().map(|&b| if b { '1' } else { '0' }).collect();
-                println!("  {}", s);
-            }
-            println!("]");
-        };
+        print!("{}", self);
+    }
+}
 
-        let print_vec = |name: &str, vec: &Array1<bool>| {
-            let s: String = vec.iter().map(|&b| if b { '1' } else { '0' }).collect();
-            println!("{}: [{}]", name, s);
-        };
+impl fmt::Display for CHFormInternalState {
+    /// Formats the matrices and vectors as 0s and 1s, followed by the statevector.
+    ///
+    /// Amplitudes are labelled by kets with qubit 0 as the rightmost bit, as in
+    /// `StabilizerCHForm::to_dirac_string`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "--- CHFormInternalState (n={}) ---", self.n_qubits)?;
 
-        let print_int_vec = |name: &str, vec: &Array1<u8>| {
-            let s: String = vec
-                .iter()
-                .map(|&v| v.to_string())
-                .collect::<Vec<_>>()
-                .join(" ");
-            println!("{}: [{}]", name, s);
+        let bits = |row: ndarray::ArrayView1<bool>| -> String {
+            row.iter().map(|&b| if b { '1' } else { '0' }).collect()
         };
 
-        print_mat("mat_g", &self.mat_g);
-        print_mat("mat_f", &self.mat_f);
-        print_mat("mat_m", &self.mat_m);
-        print_int_vec("gamma", &self.gamma);
-        print_vec("vec_v", &self.vec_v);
-        print_vec("vec_s", &self.vec_s);
-        println!("statevector: [");
+        for (name, mat) in [
+            ("mat_g", &self.mat_g),
+            ("mat_f", &self.mat_f),
+            ("mat_m", &self.mat_m),
+        ] {
+            writeln!(f, "{}: [", name)?;
+            for row in mat.rows() {
+                writeln!(f, "  {}", bits(row))?;
+            }
+            writeln!(f, "]")?;
+        }
+        let gamma = self
+            .gamma
+            .iter()
+            .map(|&v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(f, "gamma: [{}]", gamma)?;
+        writeln!(f, "vec_v: [{}]", bits(self.vec_v.view()))?;
+        writeln!(f, "vec_s: [{}]", bits(self.vec_s.view()))?;
+        writeln!(f, "statevector: [")?;
         for (i, amp) in self.statevector.iter().enumerate() {
             // e.g., |001>: +0.000000 -0.707107i
-            println!(
+            writeln!(
+                f,
                 "  |{:0width$b}>: {:+.6} {:+.6} i",
                 i,
                 amp.re,
                 amp.im,
                 width = self.n_qubits
-            );
+            )?;
         }
-        println!("]");
+        writeln!(f, "]")?;
 
-        println!("------------------------------------");
+        writeln!(f, "------------------------------------")
     }
 }
 
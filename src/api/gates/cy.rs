@@ -0,0 +1,14 @@
+use crate::StabilizerCHForm;
+
+pub trait CYGate {
+    fn apply_cy(&mut self, control: usize, target: usize);
+}
+
+impl CYGate for StabilizerCHForm {
+    /// Applies the controlled-Y gate with control qubit at index `control` and target qubit at index `target`.
+    ///
+    /// Time complexity: O(n)
+    fn apply_cy(&mut self, control: usize, target: usize) {
+        self._left_multiply_cy(control, target);
+    }
+}
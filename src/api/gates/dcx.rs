@@ -0,0 +1,14 @@
+use crate::StabilizerCHForm;
+
+pub trait DCXGate {
+    fn apply_dcx(&mut self, qarg1: usize, qarg2: usize);
+}
+
+impl DCXGate for StabilizerCHForm {
+    /// Applies the double-CNOT gate, i.e. CX(qarg1, qarg2) followed by CX(qarg2, qarg1).
+    ///
+    /// Time complexity: O(n)
+    fn apply_dcx(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_dcx(qarg1, qarg2);
+    }
+}
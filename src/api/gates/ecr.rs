@@ -0,0 +1,15 @@
+use crate::StabilizerCHForm;
+
+pub trait ECRGate {
+    fn apply_ecr(&mut self, qarg1: usize, qarg2: usize);
+}
+
+impl ECRGate for StabilizerCHForm {
+    /// Applies the echoed cross-resonance gate ECR = (X ⊗ I - Y ⊗ X)/√2,
+    /// where the first factor acts on `qarg1` (the same convention as Qiskit).
+    ///
+    /// Time complexity: O(n^2)
+    fn apply_ecr(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_ecr(qarg1, qarg2);
+    }
+}
@@ -0,0 +1,14 @@
+use crate::StabilizerCHForm;
+
+pub trait HXYGate {
+    fn apply_h_xy(&mut self, qarg: usize);
+}
+
+impl HXYGate for StabilizerCHForm {
+    /// Applies the Hadamard-like gate (X + Y)/√2 to the qubit at index `qarg`.
+    ///
+    /// Time complexity: O(n)
+    fn apply_h_xy(&mut self, qarg: usize) {
+        self._left_multiply_h_xy(qarg);
+    }
+}
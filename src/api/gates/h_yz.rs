@@ -0,0 +1,14 @@
+use crate::StabilizerCHForm;
+
+pub trait HYZGate {
+    fn apply_h_yz(&mut self, qarg: usize);
+}
+
+impl HYZGate for StabilizerCHForm {
+    /// Applies the Hadamard-like gate (Y + Z)/√2 to the qubit at index `qarg`.
+    ///
+    /// Time complexity: O(n^2)
+    fn apply_h_yz(&mut self, qarg: usize) {
+        self._left_multiply_h_yz(qarg);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::StabilizerCHForm;
+
+pub trait ISwapGate {
+    fn apply_iswap(&mut self, qarg1: usize, qarg2: usize);
+    fn apply_iswapdg(&mut self, qarg1: usize, qarg2: usize);
+}
+
+impl ISwapGate for StabilizerCHForm {
+    /// Applies the iSWAP gate between the qubits at indices `qarg1` and `qarg2`.
+    ///
+    /// Time complexity: O(n)
+    fn apply_iswap(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_iswap(qarg1, qarg2);
+    }
+
+    /// Applies the adjoint of the iSWAP gate between the qubits at indices `qarg1` and `qarg2`.
+    ///
+    /// Time complexity: O(n)
+    fn apply_iswapdg(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_iswapdg(qarg1, qarg2);
+    }
+}
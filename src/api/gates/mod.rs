@@ -1,19 +1,37 @@
 pub mod cx;
+pub mod cy;
 pub mod cz;
+pub mod dcx;
+pub mod ecr;
 pub mod h;
+pub mod h_xy;
+pub mod h_yz;
+pub mod iswap;
+pub mod pauli_product;
 pub mod s;
+pub mod single_qubit_clifford_gate;
 pub mod sqrt_x;
+pub mod sqrt_y;
 pub mod swap;
 pub mod x;
 pub mod y;
 pub mod z;
 
 pub use cx::CXGate;
+pub use cy::CYGate;
 pub use cz::CZGate;
+pub use dcx::DCXGate;
+pub use ecr::ECRGate;
 pub use h::HGate;
+pub use h_xy::HXYGate;
+pub use h_yz::HYZGate;
+pub use iswap::ISwapGate;
+pub use pauli_product::PauliProductGate;
 pub use s::SGate;
+pub use single_qubit_clifford_gate::SingleQubitCliffordGate;
 pub use x::XGate;
 pub use z::ZGate;
 pub use sqrt_x::SqrtXGate;
+pub use sqrt_y::SqrtYGate;
 pub use y::YGate;
 pub use swap::SwapGate;
@@ -0,0 +1,32 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::PauliString;
+
+pub trait PauliProductGate {
+    fn apply_pauli(&mut self, pauli: &PauliString);
+    fn apply_pauli_rotation(&mut self, pauli: &PauliString, negative: bool);
+}
+
+impl PauliProductGate for StabilizerCHForm {
+    /// Applies the multi-qubit Pauli product `pauli`, including its sign.
+    ///
+    /// Time complexity: O(w n) where w is the weight of `pauli`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pauli` does not act on exactly `n_qubits()` qubits.
+    fn apply_pauli(&mut self, pauli: &PauliString) {
+        self._left_multiply_pauli(pauli);
+    }
+
+    /// Applies the π/4 Pauli rotation exp(iπ/4 P) = (I + iP)/√2, or
+    /// exp(-iπ/4 P) = (I - iP)/√2 if `negative` is set.
+    ///
+    /// Time complexity: O(w n^2) where w is the weight of `pauli`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pauli` does not act on exactly `n_qubits()` qubits.
+    fn apply_pauli_rotation(&mut self, pauli: &PauliString, negative: bool) {
+        self._left_multiply_pauli_rotation(pauli, negative);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::SingleQubitClifford;
+
+pub trait SingleQubitCliffordGate {
+    fn apply_single_qubit_clifford(&mut self, qarg: usize, clifford: SingleQubitClifford);
+}
+
+impl SingleQubitCliffordGate for StabilizerCHForm {
+    /// Applies an arbitrary single-qubit Clifford to the qubit at index `qarg`,
+    /// using at most one Hadamard update.
+    ///
+    /// The global phase is that of `clifford.to_gates(qarg)`.
+    ///
+    /// Time complexity: O(n^2)
+    fn apply_single_qubit_clifford(&mut self, qarg: usize, clifford: SingleQubitClifford) {
+        self._left_multiply_single_qubit_clifford(qarg, &clifford);
+    }
+}
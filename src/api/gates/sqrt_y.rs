@@ -0,0 +1,22 @@
+use crate::StabilizerCHForm;
+
+pub trait SqrtYGate {
+    fn apply_sqrt_y(&mut self, qarg: usize);
+    fn apply_sqrt_ydg(&mut self, qarg: usize);
+}
+
+impl SqrtYGate for StabilizerCHForm {
+    /// Applies the √Y gate to the qubit at index `qarg`.
+    ///
+    /// Time complexity: O(n^2)
+    fn apply_sqrt_y(&mut self, qarg: usize) {
+        self._left_multiply_sqrt_y(qarg);
+    }
+
+    /// Applies the adjoint of the √Y gate to the qubit at index `qarg`.
+    ///
+    /// Time complexity: O(n^2)
+    fn apply_sqrt_ydg(&mut self, qarg: usize) {
+        self._left_multiply_sqrt_ydg(qarg);
+    }
+}
@@ -2,7 +2,9 @@ pub mod comp_basis;
 pub mod debug;
 pub mod gates;
 pub mod operations;
+pub(crate) mod qargs;
 pub mod representation;
+pub mod sampling;
 pub mod structure;
 
 pub use comp_basis::*;
@@ -10,4 +12,5 @@ pub use debug::*;
 pub use gates::*;
 pub use operations::*;
 pub use representation::*;
+pub use sampling::*;
 pub use structure::*;
@@ -0,0 +1,43 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::CliffordTableau;
+
+impl StabilizerCHForm {
+    /// Applies the Clifford operation `tableau` to the qubits `qargs`.
+    ///
+    /// Qubit `i` of the tableau acts on qubit `qargs[i]` of the state, so the
+    /// operation can target an arbitrary subset of qubits in any order. Since a
+    /// tableau does not fix a global phase, the resulting global phase is
+    /// that of `tableau.to_circuit()`.
+    ///
+    /// # Arguments
+    /// * `tableau` - The Clifford operation to apply.
+    /// * `qargs` - Distinct qubit indices, one per tableau qubit.
+    ///
+    /// # Returns
+    /// An error if `qargs` has the wrong length, repeats a qubit or is out of bounds.
+    ///
+    /// Time complexity: O(n_t^3 + n_t^2 n^2) where n_t is the number of qubits of `tableau`.
+    pub fn apply_clifford(
+        &mut self,
+        tableau: &CliffordTableau,
+        qargs: &[usize],
+    ) -> Result<(), String> {
+        if qargs.len() != tableau.n_qubits() {
+            return Err(format!(
+                "Tableau acts on {} qubits but {} were given.",
+                tableau.n_qubits(),
+                qargs.len()
+            ));
+        }
+        for (i, &q) in qargs.iter().enumerate() {
+            if q >= self.n {
+                return Err(format!("Qubit index {} out of bounds.", q));
+            }
+            if qargs[..i].contains(&q) {
+                return Err(format!("Qubit index {} is repeated.", q));
+            }
+        }
+        self._left_multiply_clifford_tableau(tableau, qargs);
+        Ok(())
+    }
+}
@@ -1,7 +1,79 @@
 use crate::StabilizerCHForm;
+use crate::api::representation::PauliString;
+use rand::Rng;
+
+/// A single-qubit Pauli measurement basis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Basis {
+    X,
+    Y,
+    Z,
+}
 
 impl StabilizerCHForm {
     pub fn measure(&mut self, qarg: usize) -> bool {
         self._measure(qarg)
     }
+
+    /// Measures the qubit at index `qarg` in the Z basis, drawing randomness from `rng`.
+    pub fn measure_with_rng<R: Rng + ?Sized>(&mut self, qarg: usize, rng: &mut R) -> bool {
+        self._measure_with_rng(qarg, rng)
+    }
+
+    /// Measures the qubit at index `qarg` in the X basis.
+    ///
+    /// Returns `false` for |+> and `true` for |->, and collapses the qubit accordingly.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn measure_x(&mut self, qarg: usize) -> bool {
+        self.measure_in_basis(qarg, Basis::X)
+    }
+
+    /// Measures the qubit at index `qarg` in the Y basis.
+    ///
+    /// Returns `false` for |+i> and `true` for |-i>, and collapses the qubit accordingly.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn measure_y(&mut self, qarg: usize) -> bool {
+        self.measure_in_basis(qarg, Basis::Y)
+    }
+
+    /// Measures the qubit at index `qarg` in the eigenbasis of `basis`.
+    ///
+    /// Returns `false` for the +1 eigenvalue and `true` for the -1 eigenvalue.
+    pub fn measure_in_basis(&mut self, qarg: usize, basis: Basis) -> bool {
+        self._measure_in_basis_with_rng(qarg, basis, &mut rand::thread_rng())
+    }
+
+    /// Same as `measure_in_basis`, drawing randomness from `rng`.
+    pub fn measure_in_basis_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        qarg: usize,
+        basis: Basis,
+        rng: &mut R,
+    ) -> bool {
+        self._measure_in_basis_with_rng(qarg, basis, rng)
+    }
+
+    /// Measures the Pauli observable `pauli` and collapses the state accordingly.
+    ///
+    /// Returns `false` for the +1 eigenvalue and `true` for the -1 eigenvalue.
+    ///
+    /// Time complexity: O(w n^2) where w is the weight of `pauli`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pauli` does not act on exactly `n_qubits()` qubits.
+    pub fn measure_pauli(&mut self, pauli: &PauliString) -> bool {
+        self._measure_pauli_with_rng(pauli, &mut rand::thread_rng())
+    }
+
+    /// Same as `measure_pauli`, drawing randomness from `rng`.
+    pub fn measure_pauli_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        pauli: &PauliString,
+        rng: &mut R,
+    ) -> bool {
+        self._measure_pauli_with_rng(pauli, rng)
+    }
 }
@@ -1,3 +1,8 @@
+pub mod apply_clifford;
 pub mod inner_product;
 pub mod measure;
+pub mod probability;
 pub mod project;
+pub mod reset;
+
+pub use measure::Basis;
@@ -0,0 +1,50 @@
+use crate::StabilizerCHForm;
+
+/// Largest number of qubits accepted by `marginal_distribution`.
+const MAX_MARGINAL_QUBITS: usize = 24;
+
+impl StabilizerCHForm {
+    /// Returns the probability of obtaining `outcome` when measuring the qubit at
+    /// index `qarg` in the Z basis, without collapsing the state.
+    ///
+    /// The result is exactly 0, 1/2 or 1.
+    ///
+    /// Time complexity: O(n)
+    pub fn probability(&self, qarg: usize, outcome: bool) -> f64 {
+        self.probability_of(&[(qarg, outcome)])
+    }
+
+    /// Returns the probability of the joint Z-basis outcome `assignment`, given as
+    /// `(qubit, outcome)` pairs for any subset of the qubits.
+    ///
+    /// The result is either 0 or a power of two 2^(-k), where k is the number of
+    /// independent random bits among the measured qubits.
+    ///
+    /// Time complexity: O(k n min(k, n)) where k is the length of `assignment`.
+    pub fn probability_of(&self, assignment: &[(usize, bool)]) -> f64 {
+        match self._probability_exponent(assignment) {
+            Some(k) => 0.5_f64.powi(k as i32),
+            None => 0.0,
+        }
+    }
+
+    /// Returns the joint distribution of Z-basis outcomes of `qubits`.
+    ///
+    /// The entry at index `i` is the probability that `qubits[j]` is measured as
+    /// bit `j` of `i` (little-endian, as in `to_statevector`).
+    ///
+    /// Time complexity: O(k n + 2^k) where k is the length of `qubits`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than 24 qubits are requested.
+    pub fn marginal_distribution(&self, qubits: &[usize]) -> Vec<f64> {
+        if qubits.len() > MAX_MARGINAL_QUBITS {
+            panic!(
+                "Marginal distributions are limited to {} qubits.",
+                MAX_MARGINAL_QUBITS
+            );
+        }
+        self._marginal_distribution(qubits)
+    }
+}
@@ -1,7 +1,33 @@
 use crate::StabilizerCHForm;
+use crate::api::operations::Basis;
 
 impl StabilizerCHForm {
     pub fn project(&mut self, qarg: usize, outcome: bool) -> Result<(), &'static str> {
         self._project(qarg, outcome)
     }
+
+    /// Projects the qubit at index `qarg` onto |+> (`outcome = false`) or |-> (`outcome = true`).
+    ///
+    /// Returns an error, leaving the state unchanged, if the outcome has zero probability.
+    pub fn project_x(&mut self, qarg: usize, outcome: bool) -> Result<(), &'static str> {
+        self._project_in_basis(qarg, Basis::X, outcome)
+    }
+
+    /// Projects the qubit at index `qarg` onto |+i> (`outcome = false`) or |-i> (`outcome = true`).
+    ///
+    /// Returns an error, leaving the state unchanged, if the outcome has zero probability.
+    pub fn project_y(&mut self, qarg: usize, outcome: bool) -> Result<(), &'static str> {
+        self._project_in_basis(qarg, Basis::Y, outcome)
+    }
+
+    /// Projects the qubit at index `qarg` onto the eigenstate of `basis` with eigenvalue
+    /// (-1)^outcome.
+    pub fn project_in_basis(
+        &mut self,
+        qarg: usize,
+        basis: Basis,
+        outcome: bool,
+    ) -> Result<(), &'static str> {
+        self._project_in_basis(qarg, basis, outcome)
+    }
 }
@@ -0,0 +1,48 @@
+use crate::StabilizerCHForm;
+use crate::api::operations::Basis;
+use rand::Rng;
+
+impl StabilizerCHForm {
+    /// Resets the qubit at index `qarg` to |0>.
+    ///
+    /// The qubit is measured in the Z basis and flipped if the outcome is 1, so
+    /// any entanglement with the other qubits is removed. This allows ancilla
+    /// qubits to be reused without rebuilding the state.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn reset(&mut self, qarg: usize) {
+        self._reset_with_rng(qarg, &mut rand::thread_rng());
+    }
+
+    /// Same as `reset`, drawing the measurement randomness from `rng`.
+    pub fn reset_with_rng<R: Rng + ?Sized>(&mut self, qarg: usize, rng: &mut R) {
+        self._reset_with_rng(qarg, rng);
+    }
+
+    /// Resets the qubit at index `qarg` to |+>.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn reset_x(&mut self, qarg: usize) {
+        self.reset_in_basis_with_rng(qarg, Basis::X, &mut rand::thread_rng());
+    }
+
+    /// Resets the qubit at index `qarg` to |+i> = (|0> + i|1>)/√2.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn reset_y(&mut self, qarg: usize) {
+        self.reset_in_basis_with_rng(qarg, Basis::Y, &mut rand::thread_rng());
+    }
+
+    /// Resets the qubit at index `qarg` to the +1 eigenstate of `basis`, drawing
+    /// the measurement randomness from `rng`.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn reset_in_basis_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        qarg: usize,
+        basis: Basis,
+        rng: &mut R,
+    ) {
+        self._reset_in_basis_with_rng(qarg, basis, rng);
+    }
+}
@@ -0,0 +1,14 @@
+//! Validation of qubit arguments shared by the public APIs.
+
+/// Checks that `qargs` are distinct qubits of an `n_qubits`-qubit register.
+pub(crate) fn check_qargs(qargs: &[usize], n_qubits: usize) -> Result<(), String> {
+    for (i, &q) in qargs.iter().enumerate() {
+        if q >= n_qubits {
+            return Err(format!("Qubit index {} out of bounds.", q));
+        }
+        if qargs[..i].contains(&q) {
+            return Err(format!("Qubit index {} is repeated.", q));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use super::CliffordCircuit;
+use crate::api::representation::CliffordGate;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Analysis utilities. All of them assume a valid circuit (see `validate`) and
+/// panic on out-of-range qubit indices.
+impl CliffordCircuit {
+    /// Returns the number of moments of gates acting on disjoint qubits, with each
+    /// gate scheduled as early as possible.
+    pub fn depth(&self) -> usize {
+        self.asap_levels(|_| true).into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns the depth counting only two-qubit gates, so single-qubit gates
+    /// do not add moments.
+    pub fn two_qubit_depth(&self) -> usize {
+        self.asap_levels(|gate| gate.qubits().len() == 2)
+            .into_iter()
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the 1-based ASAP moment of each gate. Gates rejected by `counts`
+    /// are scheduled like the others but do not occupy a moment of their own.
+    fn asap_levels<F: Fn(&CliffordGate) -> bool>(&self, counts: F) -> Vec<usize> {
+        let mut qubit_level = vec![0; self.n_qubits];
+        self.gates
+            .iter()
+            .map(|gate| {
+                let qubits = gate.qubits();
+                let level = qubits.iter().map(|&q| qubit_level[q]).max().unwrap_or(0)
+                    + counts(gate) as usize;
+                for q in qubits {
+                    qubit_level[q] = level;
+                }
+                level
+            })
+            .collect()
+    }
+
+    /// Returns the number of gates of each kind, keyed by `CliffordGate::name`.
+    pub fn gate_counts(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for gate in &self.gates {
+            *counts.entry(gate.name()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the number of two-qubit gates.
+    pub fn two_qubit_gate_count(&self) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| gate.qubits().len() == 2)
+            .count()
+    }
+
+    /// Splits the circuit into moments of non-overlapping gates, scheduling each
+    /// gate as early as possible. Gates keep their relative order within a moment.
+    pub fn layers_asap(&self) -> Vec<Vec<CliffordGate>> {
+        let levels = self.asap_levels(|_| true);
+        group_by_level(&self.gates, &levels)
+    }
+
+    /// Splits the circuit into moments of non-overlapping gates, scheduling each
+    /// gate as late as possible. Gates keep their relative order within a moment.
+    pub fn layers_alap(&self) -> Vec<Vec<CliffordGate>> {
+        let mut reversed = self.clone();
+        reversed.gates.reverse();
+        let reversed_levels = reversed.asap_levels(|_| true);
+        let depth = reversed_levels.iter().copied().max().unwrap_or(0);
+        let levels: Vec<usize> = reversed_levels
+            .into_iter()
+            .rev()
+            .map(|level| depth + 1 - level)
+            .collect();
+        group_by_level(&self.gates, &levels)
+    }
+
+    /// Returns the qubit interaction graph as a map from each pair `(a, b)` with
+    /// `a < b` to the number of two-qubit gates acting on it.
+    pub fn interaction_graph(&self) -> BTreeMap<(usize, usize), usize> {
+        let mut edges = BTreeMap::new();
+        for gate in &self.gates {
+            if let [a, b] = gate.qubits()[..] {
+                *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+        }
+        edges
+    }
+
+    /// Returns the qubits acted on by at least one gate.
+    pub fn used_qubits(&self) -> BTreeSet<usize> {
+        self.gates.iter().flat_map(|gate| gate.qubits()).collect()
+    }
+}
+
+/// Groups `gates` into moments given the 1-based moment of each gate.
+fn group_by_level(gates: &[CliffordGate], levels: &[usize]) -> Vec<Vec<CliffordGate>> {
+    let depth = levels.iter().copied().max().unwrap_or(0);
+    let mut layers = vec![Vec::new(); depth];
+    for (gate, &level) in gates.iter().zip(levels) {
+        layers[level - 1].push(gate.clone());
+    }
+    layers
+}
@@ -0,0 +1,167 @@
+use super::{CliffordCircuit, check_gate};
+use crate::api::representation::CliffordGate;
+
+/// A chainable builder for `CliffordCircuit` that validates every gate as it is added.
+///
+/// The first invalid gate is recorded and reported by `build`, so a chain of
+/// calls needs only one error check:
+///
+/// ```
+/// use stabilizer_ch_form_rust::api::CliffordCircuit;
+///
+/// let ghz = CliffordCircuit::builder(3).h(0).cx_ladder(0..3).build().unwrap();
+/// assert_eq!(ghz.gates.len(), 3);
+/// assert!(CliffordCircuit::builder(2).cx(0, 2).build().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CliffordCircuitBuilder {
+    circuit: CliffordCircuit,
+    error: Option<String>,
+}
+
+impl CliffordCircuitBuilder {
+    pub fn new(n_qubits: usize) -> Self {
+        Self {
+            circuit: CliffordCircuit::new(n_qubits),
+            error: None,
+        }
+    }
+
+    /// Returns the circuit, or the error caused by the first invalid gate.
+    pub fn build(self) -> Result<CliffordCircuit, String> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.circuit),
+        }
+    }
+
+    /// Appends `gate`, recording an error if it is invalid.
+    pub fn gate(mut self, gate: CliffordGate) -> Self {
+        if self.error.is_none()
+            && let Err(error) = check_gate(&gate, self.circuit.n_qubits)
+        {
+            self.error = Some(error);
+        }
+        self.circuit.add_gate(gate);
+        self
+    }
+
+    /// Appends every gate of `circuit`, which must act on the same number of qubits.
+    pub fn circuit(mut self, circuit: &CliffordCircuit) -> Self {
+        if circuit.n_qubits != self.circuit.n_qubits && self.error.is_none() {
+            self.error = Some(format!(
+                "Cannot append a circuit on {} qubits to one on {} qubits.",
+                circuit.n_qubits, self.circuit.n_qubits
+            ));
+        }
+        circuit
+            .gates
+            .iter()
+            .fold(self, |builder, gate| builder.gate(gate.clone()))
+    }
+
+    /// Appends `gate(q)` for every qubit `q` in `qubits`.
+    pub fn layer<I, F>(self, qubits: I, gate: F) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+        F: Fn(usize) -> CliffordGate,
+    {
+        qubits
+            .into_iter()
+            .fold(self, |builder, q| builder.gate(gate(q)))
+    }
+
+    /// Appends H to every qubit.
+    pub fn h_all(self) -> Self {
+        let n_qubits = self.circuit.n_qubits;
+        self.layer(0..n_qubits, CliffordGate::H)
+    }
+
+    /// Appends CX(q_0, q_1), CX(q_1, q_2), ... for consecutive entries of `qubits`.
+    pub fn cx_ladder<I: IntoIterator<Item = usize>>(self, qubits: I) -> Self {
+        let qubits: Vec<usize> = qubits.into_iter().collect();
+        qubits
+            .windows(2)
+            .fold(self, |builder, pair| builder.cx(pair[0], pair[1]))
+    }
+
+    pub fn h(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::H(qarg))
+    }
+
+    pub fn x(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::X(qarg))
+    }
+
+    pub fn y(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::Y(qarg))
+    }
+
+    pub fn z(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::Z(qarg))
+    }
+
+    pub fn s(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::S(qarg))
+    }
+
+    pub fn sdg(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::Sdg(qarg))
+    }
+
+    pub fn sqrt_x(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::SqrtX(qarg))
+    }
+
+    pub fn sqrt_xdg(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::SqrtXdg(qarg))
+    }
+
+    pub fn sqrt_y(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::SqrtY(qarg))
+    }
+
+    pub fn sqrt_ydg(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::SqrtYdg(qarg))
+    }
+
+    pub fn h_xy(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::HXY(qarg))
+    }
+
+    pub fn h_yz(self, qarg: usize) -> Self {
+        self.gate(CliffordGate::HYZ(qarg))
+    }
+
+    pub fn cx(self, control: usize, target: usize) -> Self {
+        self.gate(CliffordGate::CX(control, target))
+    }
+
+    pub fn cy(self, control: usize, target: usize) -> Self {
+        self.gate(CliffordGate::CY(control, target))
+    }
+
+    pub fn cz(self, control: usize, target: usize) -> Self {
+        self.gate(CliffordGate::CZ(control, target))
+    }
+
+    pub fn swap(self, qarg1: usize, qarg2: usize) -> Self {
+        self.gate(CliffordGate::Swap(qarg1, qarg2))
+    }
+
+    pub fn iswap(self, qarg1: usize, qarg2: usize) -> Self {
+        self.gate(CliffordGate::ISwap(qarg1, qarg2))
+    }
+
+    pub fn iswapdg(self, qarg1: usize, qarg2: usize) -> Self {
+        self.gate(CliffordGate::ISwapdg(qarg1, qarg2))
+    }
+
+    pub fn ecr(self, qarg1: usize, qarg2: usize) -> Self {
+        self.gate(CliffordGate::ECR(qarg1, qarg2))
+    }
+
+    pub fn dcx(self, qarg1: usize, qarg2: usize) -> Self {
+        self.gate(CliffordGate::DCX(qarg1, qarg2))
+    }
+}
@@ -0,0 +1,63 @@
+use super::CliffordCircuit;
+use crate::StabilizerCHForm;
+use crate::api::representation::CliffordTableau;
+use ndarray::Array2;
+use num_complex::Complex64;
+
+/// Largest number of qubits accepted by `to_unitary`.
+const MAX_UNITARY_QUBITS: usize = 10;
+
+impl CliffordCircuit {
+    /// Returns the tableau of the circuit's Clifford operation.
+    ///
+    /// Time complexity: O(g n) where g is the number of gates.
+    pub fn to_tableau(&self) -> Result<CliffordTableau, String> {
+        self.validate()?;
+        CliffordTableau::from_circuit(self)
+    }
+
+    /// Synthesizes a circuit implementing `tableau` up to a global phase.
+    ///
+    /// See `CliffordTableau::to_circuit`.
+    pub fn from_tableau(tableau: &CliffordTableau) -> CliffordCircuit {
+        tableau.to_circuit()
+    }
+
+    /// Returns the dense unitary matrix of the circuit, including its global phase.
+    ///
+    /// Column `j` is the statevector of the circuit applied to the basis state
+    /// |j>, with qubit 0 as the least significant bit as in `to_statevector`.
+    ///
+    /// NOTE: This builds a 2^n x 2^n matrix and is meant for testing and
+    /// verification on small circuits.
+    ///
+    /// # Returns
+    /// An error if the circuit is invalid or acts on more than 10 qubits.
+    pub fn to_unitary(&self) -> Result<Array2<Complex64>, String> {
+        self.validate()?;
+        let n = self.n_qubits;
+        if n > MAX_UNITARY_QUBITS {
+            return Err(format!(
+                "to_unitary supports at most {} qubits, got {}.",
+                MAX_UNITARY_QUBITS, n
+            ));
+        }
+        if n == 0 {
+            return Ok(Array2::from_elem((1, 1), Complex64::new(1.0, 0.0)));
+        }
+
+        let dim = 1 << n;
+        let mut unitary = Array2::zeros((dim, dim));
+        for col in 0..dim {
+            let mut state = StabilizerCHForm::new(n);
+            for q in (0..n).filter(|q| (col >> q) & 1 == 1) {
+                state._left_multiply_x(q);
+            }
+            for gate in &self.gates {
+                state._apply_gate(gate);
+            }
+            unitary.column_mut(col).assign(&state.to_statevector());
+        }
+        Ok(unitary)
+    }
+}
@@ -0,0 +1,205 @@
+use super::CliffordCircuit;
+use crate::api::representation::CliffordGate;
+use std::fmt;
+
+/// One column of the diagram: gates whose vertical spans do not overlap.
+struct Column {
+    /// `(label, qubit)` for every gate endpoint in the column.
+    labels: Vec<(&'static str, usize)>,
+    /// Inclusive qubit ranges joined by a vertical line.
+    spans: Vec<(usize, usize)>,
+}
+
+impl Column {
+    fn new() -> Self {
+        Self {
+            labels: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    fn overlaps(&self, span: (usize, usize)) -> bool {
+        self.spans
+            .iter()
+            .any(|&(lo, hi)| span.0 <= hi && lo <= span.1)
+    }
+
+    fn push(&mut self, gate: &CliffordGate) {
+        let qubits = gate.qubits();
+        for (&label, &q) in gate_labels(gate).iter().zip(&qubits) {
+            self.labels.push((label, q));
+        }
+        let span = gate_span(gate);
+        if span.0 != span.1 {
+            self.spans.push(span);
+        }
+    }
+
+    /// Odd width, so that vertical lines sit in the middle.
+    fn width(&self) -> usize {
+        let widest = self
+            .labels
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(1);
+        (widest + 2) | 1
+    }
+
+    fn is_spanned(&self, q: usize) -> bool {
+        self.spans.iter().any(|&(lo, hi)| lo <= q && q <= hi)
+    }
+
+    /// Returns the text of wire `q`.
+    fn wire(&self, q: usize) -> String {
+        let width = self.width();
+        let label = match self.labels.iter().find(|&&(_, qubit)| qubit == q) {
+            Some(&(label, _)) => label,
+            None if self.is_spanned(q) => "┼",
+            None => "─",
+        };
+        let len = label.chars().count();
+        let left = (width - len) / 2;
+        format!(
+            "{}{}{}",
+            "─".repeat(left),
+            label,
+            "─".repeat(width - len - left)
+        )
+    }
+
+    /// Returns the text between wires `q` and `q + 1`.
+    fn gap(&self, q: usize) -> String {
+        let width = self.width();
+        if self.spans.iter().any(|&(lo, hi)| lo <= q && q < hi) {
+            format!("{}│{}", " ".repeat(width / 2), " ".repeat(width / 2))
+        } else {
+            " ".repeat(width)
+        }
+    }
+}
+
+/// Returns the label of each qubit of `gate`, in the order of `CliffordGate::qubits`.
+fn gate_labels(gate: &CliffordGate) -> Vec<&'static str> {
+    match gate {
+        CliffordGate::H(_) => vec!["H"],
+        CliffordGate::X(_) => vec!["X"],
+        CliffordGate::Y(_) => vec!["Y"],
+        CliffordGate::Z(_) => vec!["Z"],
+        CliffordGate::S(_) => vec!["S"],
+        CliffordGate::Sdg(_) => vec!["S†"],
+        CliffordGate::SqrtX(_) => vec!["√X"],
+        CliffordGate::SqrtXdg(_) => vec!["√X†"],
+        CliffordGate::SqrtY(_) => vec!["√Y"],
+        CliffordGate::SqrtYdg(_) => vec!["√Y†"],
+        CliffordGate::HXY(_) => vec!["Hxy"],
+        CliffordGate::HYZ(_) => vec!["Hyz"],
+        CliffordGate::CX(_, _) => vec!["●", "⊕"],
+        CliffordGate::CY(_, _) => vec!["●", "Y"],
+        CliffordGate::CZ(_, _) => vec!["●", "●"],
+        CliffordGate::Swap(_, _) => vec!["×", "×"],
+        CliffordGate::ISwap(_, _) => vec!["iSwap", "iSwap"],
+        CliffordGate::ISwapdg(_, _) => vec!["iSwap†", "iSwap†"],
+        CliffordGate::ECR(_, _) => vec!["ECR₀", "ECR₁"],
+        CliffordGate::DCX(_, _) => vec!["DCX₀", "DCX₁"],
+    }
+}
+
+fn gate_span(gate: &CliffordGate) -> (usize, usize) {
+    let qubits = gate.qubits();
+    let lo = qubits.iter().copied().min().unwrap();
+    let hi = qubits.iter().copied().max().unwrap();
+    (lo, hi)
+}
+
+impl CliffordCircuit {
+    /// Renders the circuit as a text diagram with one wire per qubit.
+    ///
+    /// Gates are placed in their ASAP moment (see `layers_asap`); a moment is
+    /// split into several columns if vertical lines of its gates would cross.
+    /// Controls are drawn as `●`, CX targets as `⊕` and Swap as `×`; ECR and DCX
+    /// mark their first and second qubit with `₀` and `₁`.
+    ///
+    /// If `fold` is given, the diagram is split into blocks of at most `fold`
+    /// characters per line (wider if a single column does not fit), with `»` and
+    /// `«` marking where a wire continues.
+    ///
+    /// Panics on out-of-range qubit indices, like the analysis utilities.
+    pub fn draw(&self, fold: Option<usize>) -> String {
+        if self.n_qubits == 0 {
+            return String::new();
+        }
+        let mut columns: Vec<Column> = Vec::new();
+        for layer in self.layers_asap() {
+            let first = columns.len();
+            for gate in &layer {
+                let span = gate_span(gate);
+                match columns[first..].iter_mut().find(|c| !c.overlaps(span)) {
+                    Some(column) => column.push(gate),
+                    None => {
+                        let mut column = Column::new();
+                        column.push(gate);
+                        columns.push(column);
+                    }
+                }
+            }
+        }
+
+        let names: Vec<String> = (0..self.n_qubits).map(|q| format!("q{}: ", q)).collect();
+        let prefix_width = names.iter().map(|name| name.len()).max().unwrap();
+
+        // Group columns into blocks that fit into `fold`, leaving room for the
+        // prefix, the leading wire segment and the continuation markers.
+        let mut blocks: Vec<&[Column]> = Vec::new();
+        let mut start = 0;
+        let mut line_width = prefix_width + 1;
+        for (i, column) in columns.iter().enumerate() {
+            let width = column.width();
+            if let Some(fold) = fold
+                && i > start
+                && line_width + width + 1 > fold
+            {
+                blocks.push(&columns[start..i]);
+                start = i;
+                line_width = prefix_width + 1;
+            }
+            line_width += width;
+        }
+        blocks.push(&columns[start..]);
+
+        let mut lines: Vec<String> = Vec::new();
+        for (b, block) in blocks.iter().enumerate() {
+            if b > 0 {
+                lines.push(String::new());
+            }
+            let (open, close) = (
+                if b > 0 { "«" } else { "─" },
+                if b + 1 < blocks.len() { "»" } else { "" },
+            );
+            for (q, name) in names.iter().enumerate() {
+                let mut wire = format!("{:<width$}{}", name, open, width = prefix_width);
+                for column in block.iter() {
+                    wire.push_str(&column.wire(q));
+                }
+                wire.push_str(close);
+                lines.push(wire);
+                if q + 1 < self.n_qubits {
+                    let mut gap = " ".repeat(prefix_width + 1);
+                    for column in block.iter() {
+                        gap.push_str(&column.gap(q));
+                    }
+                    lines.push(gap.trim_end().to_string());
+                }
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Draws the circuit with `CliffordCircuit::draw`. A width folds the diagram,
+/// so `format!("{:80}", circuit)` produces lines of at most 80 characters.
+impl fmt::Display for CliffordCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.draw(f.width()))
+    }
+}
@@ -0,0 +1,54 @@
+use super::CliffordCircuit;
+use crate::StabilizerCHForm;
+use crate::api::representation::CliffordTableau;
+
+impl CliffordCircuit {
+    /// Decides whether `self` and `other` implement the same unitary.
+    ///
+    /// The tableaux of both circuits are compared first, which decides equality
+    /// up to a global phase. If `up_to_global_phase` is false, both circuits are
+    /// also applied to half of n Bell pairs, and the resulting Choi states
+    /// |C_U> = (U ⊗ I)|Φ> are compared with `inner_product`, since
+    /// <C_U|C_V> = Tr(U†V) / 2^n equals 1 exactly when U = V.
+    ///
+    /// Circuits on different numbers of qubits are never equivalent.
+    ///
+    /// # Returns
+    /// An error if either circuit is invalid.
+    ///
+    /// Time complexity: O(g n) up to a global phase, O(g n^2 + n^3) otherwise,
+    /// where g is the total number of gates.
+    pub fn equivalent(
+        &self,
+        other: &CliffordCircuit,
+        up_to_global_phase: bool,
+    ) -> Result<bool, String> {
+        self.validate()?;
+        other.validate()?;
+        if self.n_qubits != other.n_qubits {
+            return Ok(false);
+        }
+        if CliffordTableau::from_circuit(self)? != CliffordTableau::from_circuit(other)? {
+            return Ok(false);
+        }
+        if up_to_global_phase || self.n_qubits == 0 {
+            return Ok(true);
+        }
+        let overlap = self.choi_state().inner_product(&other.choi_state());
+        Ok((overlap.re - 1.0).abs() < 1e-8 && overlap.im.abs() < 1e-8)
+    }
+
+    /// Returns (U ⊗ I)|Φ> where |Φ> pairs qubit `i` with qubit `n + i` in a Bell state.
+    fn choi_state(&self) -> StabilizerCHForm {
+        let n = self.n_qubits;
+        let mut choi = StabilizerCHForm::new(2 * n);
+        for q in 0..n {
+            choi._left_multiply_h(q);
+            choi._left_multiply_cx(q, n + q);
+        }
+        for gate in &self.gates {
+            choi._apply_gate(gate);
+        }
+        choi
+    }
+}
@@ -1,5 +1,21 @@
 use crate::api::clifford_gate::CliffordGate;
+use crate::api::representation::CliffordTableau;
+use rand::Rng;
+use std::ops::{Add, Mul};
 
+mod analysis;
+mod builder;
+mod conversion;
+mod diagram;
+mod equivalence;
+mod optimize;
+mod resynthesize;
+mod routing;
+pub use builder::CliffordCircuitBuilder;
+pub use optimize::OptimizationReport;
+pub use resynthesize::GateSet;
+
+#[derive(Debug, Clone)]
 pub struct CliffordCircuit {
     pub n_qubits: usize,
     pub gates: Vec<CliffordGate>,
@@ -12,10 +28,33 @@ impl CliffordCircuit {
             gates: Vec::new(),
         }
     }
+
+    /// Returns a circuit implementing a uniformly random n-qubit Clifford operation.
+    ///
+    /// The operation is sampled with `CliffordTableau::random` and synthesized
+    /// with `CliffordTableau::to_circuit`. The result is reproducible for a seeded `rng`.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn random<R: Rng + ?Sized>(n_qubits: usize, rng: &mut R) -> Self {
+        CliffordTableau::random(n_qubits, rng).to_circuit()
+    }
+
     pub fn add_gate(&mut self, gate: CliffordGate) {
         self.gates.push(gate);
     }
 
+    /// Appends `gate` after checking that it acts on distinct qubits below `n_qubits`.
+    pub fn try_add_gate(&mut self, gate: CliffordGate) -> Result<(), String> {
+        check_gate(&gate, self.n_qubits)?;
+        self.add_gate(gate);
+        Ok(())
+    }
+
+    /// Returns a builder for a circuit on `n_qubits` qubits.
+    pub fn builder(n_qubits: usize) -> CliffordCircuitBuilder {
+        CliffordCircuitBuilder::new(n_qubits)
+    }
+
     pub fn add_multiple_gates(&mut self, gates: Vec<CliffordGate>) {
         for gate in gates {
             self.add_gate(gate);
@@ -30,19 +69,223 @@ impl CliffordCircuit {
         self.add_gate(CliffordGate::X(qarg));
     }
 
-    pub fn apply_s(&mut self, qarg: usize) {
-        self.add_gate(CliffordGate::S(qarg));
+    pub fn apply_y(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::Y(qarg));
     }
 
     pub fn apply_z(&mut self, qarg: usize) {
         self.add_gate(CliffordGate::Z(qarg));
     }
 
+    pub fn apply_s(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::S(qarg));
+    }
+
+    pub fn apply_sdg(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::Sdg(qarg));
+    }
+
+    pub fn apply_sqrt_x(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::SqrtX(qarg));
+    }
+
+    pub fn apply_sqrt_xdg(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::SqrtXdg(qarg));
+    }
+
+    pub fn apply_sqrt_y(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::SqrtY(qarg));
+    }
+
+    pub fn apply_sqrt_ydg(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::SqrtYdg(qarg));
+    }
+
+    pub fn apply_h_xy(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::HXY(qarg));
+    }
+
+    pub fn apply_h_yz(&mut self, qarg: usize) {
+        self.add_gate(CliffordGate::HYZ(qarg));
+    }
+
     pub fn apply_cx(&mut self, control: usize, target: usize) {
         self.add_gate(CliffordGate::CX(control, target));
     }
 
+    pub fn apply_cy(&mut self, control: usize, target: usize) {
+        self.add_gate(CliffordGate::CY(control, target));
+    }
+
     pub fn apply_cz(&mut self, control: usize, target: usize) {
         self.add_gate(CliffordGate::CZ(control, target));
     }
+
+    pub fn apply_swap(&mut self, qarg1: usize, qarg2: usize) {
+        self.add_gate(CliffordGate::Swap(qarg1, qarg2));
+    }
+
+    pub fn apply_iswap(&mut self, qarg1: usize, qarg2: usize) {
+        self.add_gate(CliffordGate::ISwap(qarg1, qarg2));
+    }
+
+    pub fn apply_iswapdg(&mut self, qarg1: usize, qarg2: usize) {
+        self.add_gate(CliffordGate::ISwapdg(qarg1, qarg2));
+    }
+
+    pub fn apply_ecr(&mut self, qarg1: usize, qarg2: usize) {
+        self.add_gate(CliffordGate::ECR(qarg1, qarg2));
+    }
+
+    pub fn apply_dcx(&mut self, qarg1: usize, qarg2: usize) {
+        self.add_gate(CliffordGate::DCX(qarg1, qarg2));
+    }
+
+    /// Checks that every gate acts on distinct qubits with indices below `n_qubits`.
+    pub fn validate(&self) -> Result<(), String> {
+        self.gates
+            .iter()
+            .try_for_each(|gate| check_gate(gate, self.n_qubits))
+    }
+
+    /// Returns the inverse circuit, with the gates inverted in reverse order.
+    pub fn inverse(&self) -> Self {
+        CliffordCircuit {
+            n_qubits: self.n_qubits,
+            gates: self.gates.iter().rev().map(CliffordGate::inverse).collect(),
+        }
+    }
+
+    /// Appends the gates of `other`, which must act on the same number of qubits.
+    pub fn append(&mut self, other: &CliffordCircuit) -> Result<(), String> {
+        if other.n_qubits != self.n_qubits {
+            return Err(format!(
+                "Cannot append a circuit on {} qubits to one on {} qubits.",
+                other.n_qubits, self.n_qubits
+            ));
+        }
+        other.validate()?;
+        self.gates.extend(other.gates.iter().cloned());
+        Ok(())
+    }
+
+    /// Returns the circuit that applies `self` and then `other`.
+    pub fn compose(&self, other: &CliffordCircuit) -> Result<Self, String> {
+        self.validate()?;
+        let mut result = self.clone();
+        result.append(other)?;
+        Ok(result)
+    }
+
+    /// Returns the circuit that applies `self` and then `other` on the qubits `qargs`,
+    /// where qubit `i` of `other` acts on `qargs[i]`.
+    pub fn compose_on(&self, other: &CliffordCircuit, qargs: &[usize]) -> Result<Self, String> {
+        self.compose(&other.remap(qargs, self.n_qubits)?)
+    }
+
+    /// Returns the circuit on `self.n_qubits + other.n_qubits` qubits that applies
+    /// `self` to the first qubits and `other`, shifted by `self.n_qubits`, to the rest.
+    pub fn tensor(&self, other: &CliffordCircuit) -> Result<Self, String> {
+        self.validate()?;
+        other.validate()?;
+        let offset = self.n_qubits;
+        let mut result = self.clone();
+        result.n_qubits += other.n_qubits;
+        result
+            .gates
+            .extend(other.gates.iter().map(|gate| gate.map_qubits(|q| q + offset)));
+        Ok(result)
+    }
+
+    /// Returns the circuit applied `k` times in a row.
+    pub fn repeat(&self, k: usize) -> Self {
+        CliffordCircuit {
+            n_qubits: self.n_qubits,
+            gates: (0..k).flat_map(|_| self.gates.iter().cloned()).collect(),
+        }
+    }
+
+    /// Returns the circuit on `n_qubits` qubits in which qubit `q` is replaced by `mapping[q]`.
+    ///
+    /// Returns an error if `mapping` does not cover every qubit, is not injective,
+    /// or maps a qubit outside `0..n_qubits`.
+    pub fn remap(&self, mapping: &[usize], n_qubits: usize) -> Result<Self, String> {
+        self.validate()?;
+        if mapping.len() != self.n_qubits {
+            return Err(format!(
+                "Mapping has {} entries but the circuit acts on {} qubits.",
+                mapping.len(),
+                self.n_qubits
+            ));
+        }
+        for (i, &q) in mapping.iter().enumerate() {
+            if q >= n_qubits {
+                return Err(format!("Qubit index {} out of bounds.", q));
+            }
+            if mapping[..i].contains(&q) {
+                return Err(format!("Qubit index {} is mapped to twice.", q));
+            }
+        }
+        Ok(CliffordCircuit {
+            n_qubits,
+            gates: self
+                .gates
+                .iter()
+                .map(|gate| gate.map_qubits(|q| mapping[q]))
+                .collect(),
+        })
+    }
+}
+
+/// Checks that `gate` acts on distinct qubits with indices below `n_qubits`.
+pub(crate) fn check_gate(gate: &CliffordGate, n_qubits: usize) -> Result<(), String> {
+    let qubits = gate.qubits();
+    if qubits.iter().any(|&q| q >= n_qubits) {
+        return Err(format!(
+            "Qubit index out of bounds in {:?} for a circuit on {} qubits.",
+            gate, n_qubits
+        ));
+    }
+    if qubits.len() == 2 && qubits[0] == qubits[1] {
+        return Err(format!("{:?} acts twice on the same qubit.", gate));
+    }
+    Ok(())
+}
+
+/// `a + b` is `a.compose(&b)`.
+///
+/// # Panics
+///
+/// Panics if the circuits act on different numbers of qubits or are invalid.
+impl Add<&CliffordCircuit> for &CliffordCircuit {
+    type Output = CliffordCircuit;
+
+    fn add(self, other: &CliffordCircuit) -> CliffordCircuit {
+        self.compose(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Add for CliffordCircuit {
+    type Output = CliffordCircuit;
+
+    fn add(self, other: CliffordCircuit) -> CliffordCircuit {
+        &self + &other
+    }
+}
+
+/// `a * k` is `a.repeat(k)`.
+impl Mul<usize> for &CliffordCircuit {
+    type Output = CliffordCircuit;
+
+    fn mul(self, k: usize) -> CliffordCircuit {
+        self.repeat(k)
+    }
+}
+
+impl Mul<usize> for CliffordCircuit {
+    type Output = CliffordCircuit;
+
+    fn mul(self, k: usize) -> CliffordCircuit {
+        self.repeat(k)
+    }
 }
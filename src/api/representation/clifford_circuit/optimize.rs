@@ -0,0 +1,218 @@
+use super::CliffordCircuit;
+use crate::api::representation::{CliffordGate, CliffordTableau, SingleQubitClifford};
+use std::collections::{HashMap, VecDeque};
+
+/// Constructor of a single-qubit gate on a given qubit.
+pub(super) type GateFn = fn(usize) -> CliffordGate;
+
+/// Single-qubit gates used to build minimal representatives, in order of preference.
+const SINGLE_QUBIT_GATES: [GateFn; 12] = [
+    CliffordGate::H,
+    CliffordGate::S,
+    CliffordGate::Sdg,
+    CliffordGate::X,
+    CliffordGate::Z,
+    CliffordGate::Y,
+    CliffordGate::SqrtX,
+    CliffordGate::SqrtXdg,
+    CliffordGate::SqrtY,
+    CliffordGate::SqrtYdg,
+    CliffordGate::HXY,
+    CliffordGate::HYZ,
+];
+
+lazy_static::lazy_static! {
+    static ref MINIMAL_WORDS: MinimalWords = MinimalWords::new(&SINGLE_QUBIT_GATES);
+}
+
+/// The shortest gate sequence over a generating set for each of the 24
+/// single-qubit Cliffords, found by breadth-first search.
+pub(super) struct MinimalWords {
+    generators: Vec<GateFn>,
+    words: HashMap<SingleQubitClifford, Vec<GateFn>>,
+}
+
+impl MinimalWords {
+    /// Builds the table for `generators`, which must generate the whole group.
+    pub(super) fn new(generators: &[GateFn]) -> Self {
+        let mut words = HashMap::new();
+        words.insert(SingleQubitClifford::IDENTITY, Vec::new());
+        let mut queue = VecDeque::from([SingleQubitClifford::IDENTITY]);
+        while let Some(element) = queue.pop_front() {
+            for &gate in generators {
+                let next = element.compose(&SingleQubitClifford::from_gate(&gate(0)).unwrap());
+                if !words.contains_key(&next) {
+                    let mut word = words[&element].clone();
+                    word.push(gate);
+                    words.insert(next, word);
+                    queue.push_back(next);
+                }
+            }
+        }
+        assert_eq!(
+            words.len(),
+            24,
+            "Generators must generate the Clifford group."
+        );
+        Self {
+            generators: generators.to_vec(),
+            words,
+        }
+    }
+
+    fn contains(&self, gate: &CliffordGate) -> bool {
+        let qarg = gate.qubits()[0];
+        self.generators
+            .iter()
+            .any(|generator| generator(qarg) == *gate)
+    }
+}
+
+/// Gate counts and depths of a circuit before and after `CliffordCircuit::optimize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptimizationReport {
+    pub gate_count_before: usize,
+    pub gate_count_after: usize,
+    pub depth_before: usize,
+    pub depth_after: usize,
+}
+
+impl CliffordCircuit {
+    /// Simplifies the circuit with peephole rules until no rule applies.
+    ///
+    /// * Pairs of gates whose product is the identity are removed, looking past
+    ///   gates they commute with (e.g. `Z(0) CX(0, 1) Z(0)` becomes `CX(0, 1)`).
+    /// * Runs of single-qubit gates on the same qubit are merged into the shortest
+    ///   gate sequence for their element of the 24-element Clifford group.
+    ///
+    /// The optimized circuit implements the same operation up to a global phase.
+    ///
+    /// # Returns
+    /// The optimized circuit and the gate counts and depths before and after,
+    /// or an error if the circuit is invalid.
+    pub fn optimize(&self) -> Result<(CliffordCircuit, OptimizationReport), String> {
+        self.validate()?;
+        let optimized = CliffordCircuit {
+            n_qubits: self.n_qubits,
+            gates: simplify(self.gates.clone(), self.n_qubits, &MINIMAL_WORDS),
+        };
+        let report = OptimizationReport {
+            gate_count_before: self.gates.len(),
+            gate_count_after: optimized.gates.len(),
+            depth_before: self.depth(),
+            depth_after: optimized.depth(),
+        };
+        Ok((optimized, report))
+    }
+}
+
+/// Returns the tableau of `gates` applied in order on the qubits they touch,
+/// relabelled to `0..k`.
+fn local_tableau(gates: &[&CliffordGate]) -> CliffordTableau {
+    let mut qubits: Vec<usize> = gates.iter().flat_map(|gate| gate.qubits()).collect();
+    qubits.sort_unstable();
+    qubits.dedup();
+    let mut tableau = CliffordTableau::identity(qubits.len());
+    for gate in gates {
+        tableau.append_gate(&gate.map_qubits(|q| qubits.binary_search(&q).unwrap()));
+    }
+    tableau
+}
+
+/// Returns whether `a` and `b` commute up to a global phase.
+fn commutes(a: &CliffordGate, b: &CliffordGate) -> bool {
+    let a_qubits = a.qubits();
+    if b.qubits().iter().all(|q| !a_qubits.contains(q)) {
+        return true;
+    }
+    local_tableau(&[a, b]) == local_tableau(&[b, a])
+}
+
+/// Returns whether `a` followed by `b` is the identity up to a global phase.
+fn cancels(a: &CliffordGate, b: &CliffordGate) -> bool {
+    let tableau = local_tableau(&[a, b]);
+    tableau == CliffordTableau::identity(tableau.n_qubits())
+}
+
+/// Cancels inverse pairs and merges single-qubit runs until the gate count stops
+/// decreasing. Every single-qubit gate of the result is a generator of `words`.
+pub(super) fn simplify(
+    mut gates: Vec<CliffordGate>,
+    n_qubits: usize,
+    words: &MinimalWords,
+) -> Vec<CliffordGate> {
+    loop {
+        let before = gates.len();
+        gates = cancel_inverse_pairs(gates);
+        gates = merge_single_qubit_runs(gates, n_qubits, words);
+        if gates.len() >= before {
+            return gates;
+        }
+    }
+}
+
+fn cancel_inverse_pairs(gates: Vec<CliffordGate>) -> Vec<CliffordGate> {
+    let mut output: Vec<Option<CliffordGate>> = Vec::with_capacity(gates.len());
+    for gate in gates {
+        let mut cancelled = false;
+        for slot in output.iter_mut().rev() {
+            let Some(previous) = slot else {
+                continue;
+            };
+            if cancels(previous, &gate) {
+                *slot = None;
+                cancelled = true;
+                break;
+            }
+            if !commutes(previous, &gate) {
+                break;
+            }
+        }
+        if !cancelled {
+            output.push(Some(gate));
+        }
+    }
+    output.into_iter().flatten().collect()
+}
+
+fn merge_single_qubit_runs(
+    gates: Vec<CliffordGate>,
+    n_qubits: usize,
+    words: &MinimalWords,
+) -> Vec<CliffordGate> {
+    let mut runs: Vec<Vec<CliffordGate>> = vec![Vec::new(); n_qubits];
+    let mut output = Vec::with_capacity(gates.len());
+    for gate in gates {
+        let qubits = gate.qubits();
+        if let [q] = qubits[..] {
+            runs[q].push(gate);
+            continue;
+        }
+        for q in qubits {
+            flush_run(&mut runs[q], q, &mut output, words);
+        }
+        output.push(gate);
+    }
+    for (q, run) in runs.iter_mut().enumerate() {
+        flush_run(run, q, &mut output, words);
+    }
+    output
+}
+
+/// Emits the minimal word for the product of `run` if it is shorter or `run` uses
+/// gates outside the generators of `words`, and `run` itself otherwise.
+fn flush_run(
+    run: &mut Vec<CliffordGate>,
+    qarg: usize,
+    output: &mut Vec<CliffordGate>,
+    words: &MinimalWords,
+) {
+    let element = SingleQubitClifford::from_gates(run).expect("Run of single-qubit gates.");
+    let word = &words.words[&element];
+    if word.len() < run.len() || !run.iter().all(|gate| words.contains(gate)) {
+        output.extend(word.iter().map(|gate| gate(qarg)));
+    } else {
+        output.append(run);
+    }
+    run.clear();
+}
@@ -0,0 +1,90 @@
+use super::CliffordCircuit;
+use super::optimize::{GateFn, MinimalWords, simplify};
+use crate::api::representation::{CliffordGate, CliffordTableau};
+
+/// A target gate set for `CliffordCircuit::resynthesize_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GateSet {
+    /// H, S, Sdg, X, Y, Z and CX.
+    #[default]
+    Standard,
+    /// H, S and CX.
+    HSCX,
+    /// H, S and CZ.
+    HSCZ,
+    /// SqrtX, S and CX.
+    SqrtXSCX,
+}
+
+impl GateSet {
+    fn single_qubit_gates(self) -> Vec<GateFn> {
+        match self {
+            GateSet::Standard => vec![
+                CliffordGate::H,
+                CliffordGate::S,
+                CliffordGate::Sdg,
+                CliffordGate::X,
+                CliffordGate::Z,
+                CliffordGate::Y,
+            ],
+            GateSet::HSCX | GateSet::HSCZ => vec![CliffordGate::H, CliffordGate::S],
+            GateSet::SqrtXSCX => vec![CliffordGate::SqrtX, CliffordGate::S],
+        }
+    }
+
+    /// Rewrites the CX and Swap gates of a synthesized circuit into the
+    /// two-qubit gate of the set.
+    fn expand_two_qubit_gate(self, gate: CliffordGate, gates: &mut Vec<CliffordGate>) {
+        match gate {
+            CliffordGate::Swap(a, b) => {
+                for (c, t) in [(a, b), (b, a), (a, b)] {
+                    self.expand_two_qubit_gate(CliffordGate::CX(c, t), gates);
+                }
+            }
+            CliffordGate::CX(c, t) if self == GateSet::HSCZ => {
+                gates.extend([
+                    CliffordGate::H(t),
+                    CliffordGate::CZ(c, t),
+                    CliffordGate::H(t),
+                ]);
+            }
+            gate => gates.push(gate),
+        }
+    }
+}
+
+impl CliffordCircuit {
+    /// Same as `resynthesize_with(GateSet::Standard)`.
+    pub fn resynthesize(&self) -> Result<CliffordCircuit, String> {
+        self.resynthesize_with(GateSet::Standard)
+    }
+
+    /// Replaces the circuit by a canonical circuit for its Clifford operation
+    /// using only the gates of `gate_set`.
+    ///
+    /// The tableau of the circuit is synthesized with the Aaronson-Gottesman
+    /// decomposition of `CliffordTableau::to_circuit`, so the result has O(n^2)
+    /// gates regardless of the original length. Two-qubit gates are then
+    /// rewritten into the target set, and the circuit is simplified as in
+    /// `optimize` with single-qubit runs expressed over the target gates.
+    ///
+    /// The result implements the same operation up to a global phase.
+    ///
+    /// Time complexity: O(g n + n^3) for the synthesis, where g is the number of
+    /// gates, plus the simplification of the m = O(n^2) synthesized gates. Each
+    /// simplification pass takes O(m^2) time, and passes repeat while the gate
+    /// count decreases, so at most m + 1 passes and O(n^6) in the worst case.
+    pub fn resynthesize_with(&self, gate_set: GateSet) -> Result<CliffordCircuit, String> {
+        self.validate()?;
+        let tableau = CliffordTableau::from_circuit(self)?;
+        let mut gates = Vec::new();
+        for gate in tableau.to_circuit().gates {
+            gate_set.expand_two_qubit_gate(gate, &mut gates);
+        }
+        let words = MinimalWords::new(&gate_set.single_qubit_gates());
+        Ok(CliffordCircuit {
+            n_qubits: self.n_qubits,
+            gates: simplify(gates, self.n_qubits, &words),
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use super::CliffordCircuit;
+use crate::api::representation::{CliffordGate, CouplingMap};
+
+impl CliffordCircuit {
+    /// Routes the circuit onto the physical qubits of `coupling_map`.
+    ///
+    /// Logical qubit `i` starts on physical qubit `i`; physical qubits beyond
+    /// `n_qubits` start as idle ancillas. Before each two-qubit gate whose qubits
+    /// are not adjacent, `Swap` gates move the first qubit along a shortest path
+    /// towards the second. `Swap` gates of the input circuit are not emitted but
+    /// absorbed into the layout, which is free.
+    ///
+    /// # Returns
+    /// The routed circuit on `coupling_map.n_qubits()` qubits and the final layout,
+    /// where `final_layout[i]` is the physical qubit holding logical qubit `i`
+    /// (ancillas included). For the routed state, `permuted(&final_layout)` gives
+    /// the state of the original circuit padded with ancillas in |0>.
+    ///
+    /// Returns an error if the circuit is invalid, does not fit on the device, or
+    /// a gate acts on disconnected qubits.
+    pub fn route(
+        &self,
+        coupling_map: &CouplingMap,
+    ) -> Result<(CliffordCircuit, Vec<usize>), String> {
+        self.validate()?;
+        let n_physical = coupling_map.n_qubits();
+        if self.n_qubits > n_physical {
+            return Err(format!(
+                "Circuit on {} qubits does not fit on a device with {} qubits.",
+                self.n_qubits, n_physical
+            ));
+        }
+
+        // layout[logical] = physical and its inverse.
+        let mut layout: Vec<usize> = (0..n_physical).collect();
+        let mut logical_at: Vec<usize> = (0..n_physical).collect();
+        let mut routed = CliffordCircuit::new(n_physical);
+
+        for gate in &self.gates {
+            let qubits = gate.qubits();
+            if let CliffordGate::Swap(a, b) = *gate {
+                layout.swap(a, b);
+                logical_at[layout[a]] = a;
+                logical_at[layout[b]] = b;
+                continue;
+            }
+            if let [a, b] = qubits[..] {
+                let target = layout[b];
+                let Some(mut distance) = coupling_map.distance(layout[a], target) else {
+                    return Err(format!("{:?} acts on disconnected physical qubits.", gate));
+                };
+                while distance > 1 {
+                    let current = layout[a];
+                    let next = coupling_map
+                        .neighbors(current)
+                        .find(|&q| coupling_map.distance(q, target) == Some(distance - 1))
+                        .expect("A shortest path continues through some neighbor.");
+                    routed.add_gate(CliffordGate::Swap(current, next));
+                    let other = logical_at[next];
+                    layout.swap(a, other);
+                    logical_at[current] = other;
+                    logical_at[next] = a;
+                    distance -= 1;
+                }
+            }
+            routed.add_gate(gate.map_qubits(|q| layout[q]));
+        }
+        Ok((routed, layout))
+    }
+}
@@ -8,7 +8,113 @@ pub enum CliffordGate {
     Sdg(usize),
     SqrtX(usize),
     SqrtXdg(usize),
+    SqrtY(usize),
+    SqrtYdg(usize),
+    HXY(usize),
+    HYZ(usize),
     CX(usize, usize),
+    CY(usize, usize),
     CZ(usize, usize),
     Swap(usize, usize),
-}
\ No newline at end of file
+    ISwap(usize, usize),
+    ISwapdg(usize, usize),
+    ECR(usize, usize),
+    DCX(usize, usize),
+}
+
+impl CliffordGate {
+    /// Returns the qubit indices the gate acts on, in argument order.
+    pub fn qubits(&self) -> Vec<usize> {
+        match self {
+            CliffordGate::H(q)
+            | CliffordGate::X(q)
+            | CliffordGate::Y(q)
+            | CliffordGate::Z(q)
+            | CliffordGate::S(q)
+            | CliffordGate::Sdg(q)
+            | CliffordGate::SqrtX(q)
+            | CliffordGate::SqrtXdg(q)
+            | CliffordGate::SqrtY(q)
+            | CliffordGate::SqrtYdg(q)
+            | CliffordGate::HXY(q)
+            | CliffordGate::HYZ(q) => vec![*q],
+            CliffordGate::CX(q1, q2)
+            | CliffordGate::CY(q1, q2)
+            | CliffordGate::CZ(q1, q2)
+            | CliffordGate::Swap(q1, q2)
+            | CliffordGate::ISwap(q1, q2)
+            | CliffordGate::ISwapdg(q1, q2)
+            | CliffordGate::ECR(q1, q2)
+            | CliffordGate::DCX(q1, q2) => vec![*q1, *q2],
+        }
+    }
+
+    /// Returns the same gate acting on `mapping(q)` instead of each qubit `q`.
+    pub fn map_qubits<F: Fn(usize) -> usize>(&self, mapping: F) -> CliffordGate {
+        match *self {
+            CliffordGate::H(q) => CliffordGate::H(mapping(q)),
+            CliffordGate::X(q) => CliffordGate::X(mapping(q)),
+            CliffordGate::Y(q) => CliffordGate::Y(mapping(q)),
+            CliffordGate::Z(q) => CliffordGate::Z(mapping(q)),
+            CliffordGate::S(q) => CliffordGate::S(mapping(q)),
+            CliffordGate::Sdg(q) => CliffordGate::Sdg(mapping(q)),
+            CliffordGate::SqrtX(q) => CliffordGate::SqrtX(mapping(q)),
+            CliffordGate::SqrtXdg(q) => CliffordGate::SqrtXdg(mapping(q)),
+            CliffordGate::SqrtY(q) => CliffordGate::SqrtY(mapping(q)),
+            CliffordGate::SqrtYdg(q) => CliffordGate::SqrtYdg(mapping(q)),
+            CliffordGate::HXY(q) => CliffordGate::HXY(mapping(q)),
+            CliffordGate::HYZ(q) => CliffordGate::HYZ(mapping(q)),
+            CliffordGate::CX(q1, q2) => CliffordGate::CX(mapping(q1), mapping(q2)),
+            CliffordGate::CY(q1, q2) => CliffordGate::CY(mapping(q1), mapping(q2)),
+            CliffordGate::CZ(q1, q2) => CliffordGate::CZ(mapping(q1), mapping(q2)),
+            CliffordGate::Swap(q1, q2) => CliffordGate::Swap(mapping(q1), mapping(q2)),
+            CliffordGate::ISwap(q1, q2) => CliffordGate::ISwap(mapping(q1), mapping(q2)),
+            CliffordGate::ISwapdg(q1, q2) => CliffordGate::ISwapdg(mapping(q1), mapping(q2)),
+            CliffordGate::ECR(q1, q2) => CliffordGate::ECR(mapping(q1), mapping(q2)),
+            CliffordGate::DCX(q1, q2) => CliffordGate::DCX(mapping(q1), mapping(q2)),
+        }
+    }
+
+    /// Returns the lowercase name of the gate kind, as used by the QASM parser.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CliffordGate::H(_) => "h",
+            CliffordGate::X(_) => "x",
+            CliffordGate::Y(_) => "y",
+            CliffordGate::Z(_) => "z",
+            CliffordGate::S(_) => "s",
+            CliffordGate::Sdg(_) => "sdg",
+            CliffordGate::SqrtX(_) => "sx",
+            CliffordGate::SqrtXdg(_) => "sxdg",
+            CliffordGate::SqrtY(_) => "sy",
+            CliffordGate::SqrtYdg(_) => "sydg",
+            CliffordGate::HXY(_) => "h_xy",
+            CliffordGate::HYZ(_) => "h_yz",
+            CliffordGate::CX(..) => "cx",
+            CliffordGate::CY(..) => "cy",
+            CliffordGate::CZ(..) => "cz",
+            CliffordGate::Swap(..) => "swap",
+            CliffordGate::ISwap(..) => "iswap",
+            CliffordGate::ISwapdg(..) => "iswapdg",
+            CliffordGate::ECR(..) => "ecr",
+            CliffordGate::DCX(..) => "dcx",
+        }
+    }
+
+    /// Returns the inverse gate, which is always a single gate of this enum.
+    pub fn inverse(&self) -> CliffordGate {
+        match *self {
+            CliffordGate::S(q) => CliffordGate::Sdg(q),
+            CliffordGate::Sdg(q) => CliffordGate::S(q),
+            CliffordGate::SqrtX(q) => CliffordGate::SqrtXdg(q),
+            CliffordGate::SqrtXdg(q) => CliffordGate::SqrtX(q),
+            CliffordGate::SqrtY(q) => CliffordGate::SqrtYdg(q),
+            CliffordGate::SqrtYdg(q) => CliffordGate::SqrtY(q),
+            CliffordGate::ISwap(q1, q2) => CliffordGate::ISwapdg(q1, q2),
+            CliffordGate::ISwapdg(q1, q2) => CliffordGate::ISwap(q1, q2),
+            CliffordGate::DCX(q1, q2) => CliffordGate::DCX(q2, q1),
+            // The remaining gates are Hermitian.
+            ref gate => gate.clone(),
+        }
+    }
+}
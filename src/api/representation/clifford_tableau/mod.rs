@@ -0,0 +1,316 @@
+use crate::api::representation::{CliffordCircuit, CliffordGate, Pauli, PauliString};
+use ndarray::{Array1, Array2};
+
+mod random;
+
+/// An n-qubit Clifford operation given by its action on Pauli operators.
+///
+/// The layout follows Qiskit's `Clifford`: row `i` is the image of `X_i`
+/// (destabilizer) and row `n + i` is the image of `Z_i` (stabilizer) under
+/// conjugation `P -> C P C†`. Columns `0..n` hold the X bits and `n..2n` the Z
+/// bits of each image, and `phases[r]` is set if the image of row `r` carries a
+/// minus sign. The global phase of the operation is not represented.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliffordTableau {
+    n: usize,
+    symplectic: Array2<bool>,
+    phases: Array1<bool>,
+}
+
+impl CliffordTableau {
+    /// Creates a tableau from a `2n x 2n` symplectic matrix and `2n` phase bits.
+    ///
+    /// # Returns
+    /// An error if the shapes do not match or the rows do not satisfy the
+    /// Pauli commutation relations.
+    pub fn new(symplectic: Array2<bool>, phases: Array1<bool>) -> Result<Self, String> {
+        let (rows, cols) = symplectic.dim();
+        if rows != cols || rows % 2 != 0 || rows == 0 {
+            return Err(format!(
+                "Symplectic matrix must be 2n x 2n with n > 0, got {} x {}.",
+                rows, cols
+            ));
+        }
+        if phases.len() != rows {
+            return Err(format!(
+                "Expected {} phase bits, got {}.",
+                rows,
+                phases.len()
+            ));
+        }
+        let n = rows / 2;
+        for i in 0..rows {
+            for j in i..rows {
+                let anticommute = (0..n).fold(false, |acc, k| {
+                    acc ^ (symplectic[[i, k]] & symplectic[[j, n + k]])
+                        ^ (symplectic[[i, n + k]] & symplectic[[j, k]])
+                });
+                if anticommute != (j == i + n && i < n) {
+                    return Err("Matrix is not symplectic.".to_string());
+                }
+            }
+        }
+        Ok(Self {
+            n,
+            symplectic,
+            phases,
+        })
+    }
+
+    /// Returns the tableau of the identity on `n_qubits` qubits.
+    pub fn identity(n_qubits: usize) -> Self {
+        Self {
+            n: n_qubits,
+            symplectic: Array2::from_shape_fn((2 * n_qubits, 2 * n_qubits), |(i, j)| i == j),
+            phases: Array1::from_elem(2 * n_qubits, false),
+        }
+    }
+
+    /// Computes the tableau of a Clifford circuit.
+    ///
+    /// Time complexity: O(g n) where g is the number of gates.
+    pub fn from_circuit(circuit: &CliffordCircuit) -> Result<Self, String> {
+        let mut tableau = Self::identity(circuit.n_qubits);
+        for gate in &circuit.gates {
+            if gate.qubits().iter().any(|&q| q >= circuit.n_qubits) {
+                return Err(format!("Qubit index out of bounds in {:?}.", gate));
+            }
+            tableau.append_gate(gate);
+        }
+        Ok(tableau)
+    }
+
+    pub fn n_qubits(&self) -> usize {
+        self.n
+    }
+
+    pub fn symplectic(&self) -> &Array2<bool> {
+        &self.symplectic
+    }
+
+    pub fn phases(&self) -> &Array1<bool> {
+        &self.phases
+    }
+
+    /// Returns the image of `X_qarg`.
+    pub fn destabilizer(&self, qarg: usize) -> PauliString {
+        self.row_to_pauli_string(qarg)
+    }
+
+    /// Returns the image of `Z_qarg`.
+    pub fn stabilizer(&self, qarg: usize) -> PauliString {
+        self.row_to_pauli_string(self.n + qarg)
+    }
+
+    fn row_to_pauli_string(&self, row: usize) -> PauliString {
+        let paulis = (0..self.n)
+            .map(|q| {
+                Pauli::from_bits(
+                    self.symplectic[[row, q]],
+                    self.symplectic[[row, self.n + q]],
+                )
+            })
+            .collect();
+        PauliString::new(paulis, self.phases[row])
+    }
+
+    /// Updates the tableau to that of `gate` applied after the current operation.
+    ///
+    /// Panics if a qubit index is out of bounds.
+    ///
+    /// Time complexity: O(n)
+    pub fn append_gate(&mut self, gate: &CliffordGate) {
+        match *gate {
+            CliffordGate::H(q) => self.update_column(q, |x, z| (z, x, x & z)),
+            CliffordGate::S(q) => self.update_column(q, |x, z| (x, z ^ x, x & z)),
+            CliffordGate::Sdg(q) => self.update_column(q, |x, z| (x, z ^ x, x & !z)),
+            CliffordGate::SqrtX(q) => self.update_column(q, |x, z| (x ^ z, z, z & !x)),
+            CliffordGate::SqrtXdg(q) => self.update_column(q, |x, z| (x ^ z, z, x & z)),
+            CliffordGate::X(q) => self.update_column(q, |x, z| (x, z, z)),
+            CliffordGate::Y(q) => self.update_column(q, |x, z| (x, z, x ^ z)),
+            CliffordGate::Z(q) => self.update_column(q, |x, z| (x, z, x)),
+            CliffordGate::CX(c, t) => {
+                let n = self.n;
+                for r in 0..2 * n {
+                    let (xc, zc) = (self.symplectic[[r, c]], self.symplectic[[r, n + c]]);
+                    let (xt, zt) = (self.symplectic[[r, t]], self.symplectic[[r, n + t]]);
+                    self.phases[r] ^= xc & zt & !(xt ^ zc);
+                    self.symplectic[[r, t]] = xt ^ xc;
+                    self.symplectic[[r, n + c]] = zc ^ zt;
+                }
+            }
+            CliffordGate::CZ(a, b) => {
+                let n = self.n;
+                for r in 0..2 * n {
+                    let (xa, za) = (self.symplectic[[r, a]], self.symplectic[[r, n + a]]);
+                    let (xb, zb) = (self.symplectic[[r, b]], self.symplectic[[r, n + b]]);
+                    self.phases[r] ^= xa & xb & (za ^ zb);
+                    self.symplectic[[r, n + a]] = za ^ xb;
+                    self.symplectic[[r, n + b]] = zb ^ xa;
+                }
+            }
+            CliffordGate::Swap(a, b) => {
+                let n = self.n;
+                for r in 0..2 * n {
+                    self.symplectic.swap([r, a], [r, b]);
+                    self.symplectic.swap([r, n + a], [r, n + b]);
+                }
+            }
+            CliffordGate::SqrtY(q) => self.append_gates(&[CliffordGate::Z(q), CliffordGate::H(q)]),
+            CliffordGate::SqrtYdg(q) => {
+                self.append_gates(&[CliffordGate::H(q), CliffordGate::Z(q)])
+            }
+            CliffordGate::HXY(q) => self.append_gates(&[CliffordGate::Sdg(q), CliffordGate::X(q)]),
+            CliffordGate::HYZ(q) => {
+                self.append_gates(&[CliffordGate::Sdg(q), CliffordGate::H(q), CliffordGate::S(q)])
+            }
+            CliffordGate::CY(c, t) => self.append_gates(&[
+                CliffordGate::Sdg(t),
+                CliffordGate::CX(c, t),
+                CliffordGate::S(t),
+            ]),
+            CliffordGate::ISwap(a, b) => self.append_gates(&[
+                CliffordGate::CZ(a, b),
+                CliffordGate::Swap(a, b),
+                CliffordGate::S(a),
+                CliffordGate::S(b),
+            ]),
+            CliffordGate::ISwapdg(a, b) => self.append_gates(&[
+                CliffordGate::Sdg(a),
+                CliffordGate::Sdg(b),
+                CliffordGate::Swap(a, b),
+                CliffordGate::CZ(a, b),
+            ]),
+            CliffordGate::ECR(a, b) => self.append_gates(&[
+                CliffordGate::H(b),
+                CliffordGate::S(a),
+                CliffordGate::S(b),
+                CliffordGate::CZ(a, b),
+                CliffordGate::H(b),
+                CliffordGate::X(a),
+            ]),
+            CliffordGate::DCX(a, b) => {
+                self.append_gates(&[CliffordGate::CX(a, b), CliffordGate::CX(b, a)])
+            }
+        }
+    }
+
+    fn append_gates(&mut self, gates: &[CliffordGate]) {
+        for gate in gates {
+            self.append_gate(gate);
+        }
+    }
+
+    /// Applies `update(x, z) -> (x', z', phase_flip)` to column `q` of every row.
+    fn update_column<F: Fn(bool, bool) -> (bool, bool, bool)>(&mut self, q: usize, update: F) {
+        let n = self.n;
+        for r in 0..2 * n {
+            let (x, z, flip) = update(self.symplectic[[r, q]], self.symplectic[[r, n + q]]);
+            self.symplectic[[r, q]] = x;
+            self.symplectic[[r, n + q]] = z;
+            self.phases[r] ^= flip;
+        }
+    }
+
+    /// Synthesizes a circuit of H, S, CX, Swap and Pauli gates implementing the
+    /// tableau up to global phase.
+    ///
+    /// See Aaronson and Gottesman, arXiv:quant-ph/0406196, following Qiskit's
+    /// `synth_clifford_ag`.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn to_circuit(&self) -> CliffordCircuit {
+        let mut reduced = self.clone();
+        let mut gates = Vec::new();
+        for q in 0..self.n {
+            reduced.set_qubit_x_true(q, &mut gates);
+            reduced.set_destabilizer_x_zero(q, &mut gates);
+            reduced.set_stabilizer_z_zero(q, &mut gates);
+        }
+        for q in 0..self.n {
+            if reduced.phases[q] {
+                reduced.push_gate(CliffordGate::Z(q), &mut gates);
+            }
+            if reduced.phases[self.n + q] {
+                reduced.push_gate(CliffordGate::X(q), &mut gates);
+            }
+        }
+
+        // The gates reduce the tableau to the identity, so the circuit is their inverse.
+        let mut circuit = CliffordCircuit::new(self.n);
+        for gate in gates.into_iter().rev() {
+            circuit.add_gate(match gate {
+                CliffordGate::S(q) => CliffordGate::Sdg(q),
+                other => other,
+            });
+        }
+        circuit
+    }
+
+    fn push_gate(&mut self, gate: CliffordGate, gates: &mut Vec<CliffordGate>) {
+        self.append_gate(&gate);
+        gates.push(gate);
+    }
+
+    /// Makes the X bit of qubit `q` set in destabilizer `q`.
+    fn set_qubit_x_true(&mut self, q: usize, gates: &mut Vec<CliffordGate>) {
+        let n = self.n;
+        if self.symplectic[[q, q]] {
+            return;
+        }
+        if let Some(i) = (q + 1..n).find(|&i| self.symplectic[[q, i]]) {
+            self.push_gate(CliffordGate::Swap(i, q), gates);
+            return;
+        }
+        if let Some(i) = (q..n).find(|&i| self.symplectic[[q, n + i]]) {
+            self.push_gate(CliffordGate::H(i), gates);
+            if i != q {
+                self.push_gate(CliffordGate::Swap(i, q), gates);
+            }
+        }
+    }
+
+    /// Reduces destabilizer `q` to `X_q`.
+    fn set_destabilizer_x_zero(&mut self, q: usize, gates: &mut Vec<CliffordGate>) {
+        let n = self.n;
+        for i in q + 1..n {
+            if self.symplectic[[q, i]] {
+                self.push_gate(CliffordGate::CX(q, i), gates);
+            }
+        }
+        if (q..n).any(|i| self.symplectic[[q, n + i]]) {
+            if !self.symplectic[[q, n + q]] {
+                self.push_gate(CliffordGate::S(q), gates);
+            }
+            for i in q + 1..n {
+                if self.symplectic[[q, n + i]] {
+                    self.push_gate(CliffordGate::CX(i, q), gates);
+                }
+            }
+            self.push_gate(CliffordGate::S(q), gates);
+        }
+    }
+
+    /// Reduces stabilizer `q` to `Z_q`.
+    fn set_stabilizer_z_zero(&mut self, q: usize, gates: &mut Vec<CliffordGate>) {
+        let n = self.n;
+        let row = n + q;
+        for i in q + 1..n {
+            if self.symplectic[[row, n + i]] {
+                self.push_gate(CliffordGate::CX(i, q), gates);
+            }
+        }
+        if (q..n).any(|i| self.symplectic[[row, i]]) {
+            self.push_gate(CliffordGate::H(q), gates);
+            for i in q + 1..n {
+                if self.symplectic[[row, i]] {
+                    self.push_gate(CliffordGate::CX(q, i), gates);
+                }
+            }
+            if self.symplectic[[row, n + q]] {
+                self.push_gate(CliffordGate::S(q), gates);
+            }
+            self.push_gate(CliffordGate::H(q), gates);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::CliffordTableau;
+use ndarray::{Array1, Array2, s};
+use rand::Rng;
+
+impl CliffordTableau {
+    /// Samples a uniformly random n-qubit Clifford operation.
+    ///
+    /// Uses the canonical form of Bravyi and Maslov (arXiv:2003.09412),
+    /// following Qiskit's `random_clifford`. The result is reproducible for a
+    /// seeded `rng`.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn random<R: Rng + ?Sized>(n_qubits: usize, rng: &mut R) -> Self {
+        let n = n_qubits;
+        let (hadamard, perm) = sample_quantum_mallows(n, rng);
+
+        let mut gamma1 = Array2::from_shape_fn((n, n), |(i, j)| i == j && rng.gen_bool(0.5));
+        let mut gamma2 = Array2::from_shape_fn((n, n), |(i, j)| i == j && rng.gen_bool(0.5));
+        let mut delta1 = Array2::from_shape_fn((n, n), |(i, j)| i == j);
+        let mut delta2 = delta1.clone();
+        fill_lower_triangle(&mut gamma1, rng, true);
+        fill_lower_triangle(&mut gamma2, rng, true);
+        fill_lower_triangle(&mut delta1, rng, false);
+        fill_lower_triangle(&mut delta2, rng, false);
+
+        let table1 = block_table(&gamma1, &delta1);
+        let table2 = block_table(&gamma2, &delta2);
+
+        // Permute the qubits, then apply the layer of Hadamards by swapping
+        // the X and Z rows of the selected qubits.
+        let mut table = Array2::from_elem((2 * n, 2 * n), false);
+        for i in 0..n {
+            let (x_row, z_row) = if hadamard[i] {
+                (n + perm[i], perm[i])
+            } else {
+                (perm[i], n + perm[i])
+            };
+            table.row_mut(i).assign(&table2.row(x_row));
+            table.row_mut(n + i).assign(&table2.row(z_row));
+        }
+
+        let symplectic = Array2::from_shape_fn((2 * n, 2 * n), |(i, j)| {
+            (0..2 * n).fold(false, |acc, k| acc ^ (table1[[i, k]] & table[[k, j]]))
+        });
+        let phases = Array1::from_shape_fn(2 * n, |_| rng.gen_bool(0.5));
+        Self::new(symplectic, phases).expect("Bravyi-Maslov sampling yields a symplectic matrix.")
+    }
+}
+
+/// Samples the Hadamard layer and qubit permutation from the quantum Mallows distribution.
+fn sample_quantum_mallows<R: Rng + ?Sized>(n: usize, rng: &mut R) -> (Vec<bool>, Vec<usize>) {
+    let mut hadamard = vec![false; n];
+    let mut perm = vec![0; n];
+    let mut remaining: Vec<usize> = (0..n).collect();
+    for i in 0..n {
+        let m = n - i;
+        let eps = 4f64.powi(-(m as i32));
+        let r: f64 = rng.gen_range(0.0..1.0);
+        let index = -(r + (1.0 - r) * eps).log2().ceil() as i64;
+        let index = index.max(0) as usize;
+        hadamard[i] = index < m;
+        let k = if index < m { index } else { 2 * m - index - 1 };
+        perm[i] = remaining.remove(k);
+    }
+    (hadamard, perm)
+}
+
+/// Fills the strictly lower triangle of `mat` with random bits, mirrored to
+/// the upper triangle if `symmetric` is set.
+fn fill_lower_triangle<R: Rng + ?Sized>(mat: &mut Array2<bool>, rng: &mut R, symmetric: bool) {
+    let dim = mat.nrows();
+    for i in 0..dim {
+        for j in 0..i {
+            let bit = rng.gen_bool(0.5);
+            mat[[i, j]] = bit;
+            if symmetric {
+                mat[[j, i]] = bit;
+            }
+        }
+    }
+}
+
+/// Returns `[[delta, 0], [gamma delta, (delta^{-1})^T]]` over GF(2).
+fn block_table(gamma: &Array2<bool>, delta: &Array2<bool>) -> Array2<bool> {
+    let n = delta.nrows();
+    let inverse = invert_lower_triangular(delta);
+    let mut table = Array2::from_elem((2 * n, 2 * n), false);
+    table.slice_mut(s![..n, ..n]).assign(delta);
+    for i in 0..n {
+        for j in 0..n {
+            table[[n + i, j]] = (0..n).fold(false, |acc, k| acc ^ (gamma[[i, k]] & delta[[k, j]]));
+            table[[n + i, n + j]] = inverse[[j, i]];
+        }
+    }
+    table
+}
+
+/// Inverts a unit lower-triangular matrix over GF(2) by forward substitution.
+fn invert_lower_triangular(mat: &Array2<bool>) -> Array2<bool> {
+    let n = mat.nrows();
+    let mut inverse = Array2::from_elem((n, n), false);
+    for col in 0..n {
+        inverse[[col, col]] = true;
+        for row in col + 1..n {
+            inverse[[row, col]] =
+                (col..row).fold(false, |acc, k| acc ^ (mat[[row, k]] & inverse[[k, col]]));
+        }
+    }
+    inverse
+}
@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+
+/// An undirected connectivity graph of physical qubits.
+///
+/// Two-qubit gates may only act on qubits joined by an edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouplingMap {
+    n_qubits: usize,
+    edges: Vec<(usize, usize)>,
+    /// `neighbors[q]` lists the qubits adjacent to `q` in increasing order.
+    neighbors: Vec<Vec<usize>>,
+    /// `distances[a][b]` is the number of edges on a shortest path, if any.
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl CouplingMap {
+    /// Creates a coupling map on `n_qubits` physical qubits.
+    ///
+    /// Edges are undirected, so `(a, b)` also allows gates on `(b, a)`.
+    ///
+    /// # Returns
+    /// An error if an edge is a self-loop or refers to a qubit out of bounds.
+    pub fn new(n_qubits: usize, edges: &[(usize, usize)]) -> Result<Self, String> {
+        let mut normalized = Vec::new();
+        for &(a, b) in edges {
+            if a >= n_qubits || b >= n_qubits {
+                return Err(format!("Edge ({}, {}) is out of bounds.", a, b));
+            }
+            if a == b {
+                return Err(format!("Edge ({}, {}) is a self-loop.", a, b));
+            }
+            normalized.push((a.min(b), a.max(b)));
+        }
+        normalized.sort_unstable();
+        normalized.dedup();
+
+        // The edges are sorted, so every list is built in increasing order.
+        let mut neighbors = vec![Vec::new(); n_qubits];
+        for &(a, b) in &normalized {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+        let distances = (0..n_qubits)
+            .map(|source| breadth_first_distances(&neighbors, source))
+            .collect();
+        Ok(Self {
+            n_qubits,
+            edges: normalized,
+            neighbors,
+            distances,
+        })
+    }
+
+    /// Returns the path 0 - 1 - ... - (n - 1).
+    pub fn line(n_qubits: usize) -> Self {
+        let edges: Vec<_> = (1..n_qubits).map(|q| (q - 1, q)).collect();
+        Self::new(n_qubits, &edges).unwrap()
+    }
+
+    /// Returns the cycle 0 - 1 - ... - (n - 1) - 0.
+    pub fn ring(n_qubits: usize) -> Self {
+        let mut edges: Vec<_> = (1..n_qubits).map(|q| (q - 1, q)).collect();
+        if n_qubits > 2 {
+            edges.push((n_qubits - 1, 0));
+        }
+        Self::new(n_qubits, &edges).unwrap()
+    }
+
+    /// Returns a `rows x cols` grid where qubit `r * cols + c` sits at row `r`, column `c`.
+    pub fn grid(rows: usize, cols: usize) -> Self {
+        let mut edges = Vec::new();
+        for r in 0..rows {
+            for c in 0..cols {
+                let q = r * cols + c;
+                if c + 1 < cols {
+                    edges.push((q, q + 1));
+                }
+                if r + 1 < rows {
+                    edges.push((q, q + cols));
+                }
+            }
+        }
+        Self::new(rows * cols, &edges).unwrap()
+    }
+
+    pub fn n_qubits(&self) -> usize {
+        self.n_qubits
+    }
+
+    /// Returns the edges as `(a, b)` pairs with `a < b`.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    pub fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        self.distance(a, b) == Some(1)
+    }
+
+    /// Returns the length of a shortest path between `a` and `b`, or `None`
+    /// if they are not connected.
+    pub fn distance(&self, a: usize, b: usize) -> Option<usize> {
+        self.distances[a][b]
+    }
+
+    /// Returns the neighbors of `qubit` in increasing order.
+    pub fn neighbors(&self, qubit: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbors[qubit].iter().copied()
+    }
+}
+
+fn breadth_first_distances(neighbors: &[Vec<usize>], source: usize) -> Vec<Option<usize>> {
+    let mut distances = vec![None; neighbors.len()];
+    distances[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(q) = queue.pop_front() {
+        let next = distances[q].map(|d| d + 1);
+        for &neighbor in &neighbors[q] {
+            if distances[neighbor].is_none() {
+                distances[neighbor] = next;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    distances
+}
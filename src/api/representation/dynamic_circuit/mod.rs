@@ -0,0 +1,225 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::{CliffordCircuit, CliffordGate, PauliString};
+use rand::Rng;
+
+/// A classical condition that holds when the bits `cbits` (little-endian) encode `value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassicalCondition {
+    pub cbits: Vec<usize>,
+    pub value: u64,
+}
+
+impl ClassicalCondition {
+    /// Returns a condition on the single classical bit `cbit`.
+    pub fn bit(cbit: usize, value: bool) -> Self {
+        Self {
+            cbits: vec![cbit],
+            value: value as u64,
+        }
+    }
+
+    /// Returns whether the condition holds for the classical register `record`.
+    pub fn is_satisfied(&self, record: &[bool]) -> bool {
+        if self.cbits.len() < u64::BITS as usize && self.value >> self.cbits.len() != 0 {
+            return false;
+        }
+        self.cbits
+            .iter()
+            .enumerate()
+            .all(|(i, &c)| record[c] == ((self.value >> i) & 1 == 1))
+    }
+}
+
+/// A single instruction of a `DynamicCircuit`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircuitInstruction {
+    /// A unitary Clifford gate.
+    Gate(CliffordGate),
+    /// Measures `qarg` in the Z basis and stores the outcome in `cbit`.
+    Measure { qarg: usize, cbit: usize },
+    /// Resets `qarg` to |0>.
+    Reset(usize),
+    /// Measures a Pauli observable and stores the outcome in `cbit`.
+    MeasurePauli { pauli: PauliString, cbit: usize },
+    /// Applies `gate` only if `condition` holds when the instruction is reached.
+    Conditional {
+        condition: ClassicalCondition,
+        gate: CliffordGate,
+    },
+}
+
+/// A Clifford circuit with mid-circuit measurements, resets and classical control.
+///
+/// Measurement outcomes are stored in a classical register of `n_clbits` bits,
+/// where `false` stands for the +1 eigenvalue (|0> for Z measurements).
+#[derive(Debug, Clone)]
+pub struct DynamicCircuit {
+    pub n_qubits: usize,
+    pub n_clbits: usize,
+    pub instructions: Vec<CircuitInstruction>,
+}
+
+/// The outcome of executing a `DynamicCircuit`.
+#[derive(Debug, Clone)]
+pub struct ExecutionResult {
+    pub state: StabilizerCHForm,
+    pub classical_register: Vec<bool>,
+}
+
+impl DynamicCircuit {
+    pub fn new(n_qubits: usize, n_clbits: usize) -> Self {
+        DynamicCircuit {
+            n_qubits,
+            n_clbits,
+            instructions: Vec::new(),
+        }
+    }
+
+    pub fn add_instruction(&mut self, instruction: CircuitInstruction) {
+        self.instructions.push(instruction);
+    }
+
+    pub fn add_gate(&mut self, gate: CliffordGate) {
+        self.add_instruction(CircuitInstruction::Gate(gate));
+    }
+
+    pub fn add_measure(&mut self, qarg: usize, cbit: usize) {
+        self.add_instruction(CircuitInstruction::Measure { qarg, cbit });
+    }
+
+    pub fn add_reset(&mut self, qarg: usize) {
+        self.add_instruction(CircuitInstruction::Reset(qarg));
+    }
+
+    pub fn add_measure_pauli(&mut self, pauli: PauliString, cbit: usize) {
+        self.add_instruction(CircuitInstruction::MeasurePauli { pauli, cbit });
+    }
+
+    pub fn add_conditional_gate(&mut self, condition: ClassicalCondition, gate: CliffordGate) {
+        self.add_instruction(CircuitInstruction::Conditional { condition, gate });
+    }
+
+    /// Checks that every qubit and classical bit index is in range.
+    pub fn validate(&self) -> Result<(), String> {
+        let check_qubits = |gate: &CliffordGate| {
+            if gate.qubits().iter().any(|&q| q >= self.n_qubits) {
+                Err(format!("Qubit index out of bounds in {:?}.", gate))
+            } else {
+                Ok(())
+            }
+        };
+        let check_cbit = |cbit: usize| {
+            if cbit >= self.n_clbits {
+                Err(format!("Classical bit index {} out of bounds.", cbit))
+            } else {
+                Ok(())
+            }
+        };
+
+        for instruction in &self.instructions {
+            match instruction {
+                CircuitInstruction::Gate(gate) => check_qubits(gate)?,
+                CircuitInstruction::Measure { qarg, cbit } => {
+                    if *qarg >= self.n_qubits {
+                        return Err(format!("Qubit index {} out of bounds.", qarg));
+                    }
+                    check_cbit(*cbit)?;
+                }
+                CircuitInstruction::Reset(qarg) => {
+                    if *qarg >= self.n_qubits {
+                        return Err(format!("Qubit index {} out of bounds.", qarg));
+                    }
+                }
+                CircuitInstruction::MeasurePauli { pauli, cbit } => {
+                    if pauli.n_qubits() != self.n_qubits {
+                        return Err(format!(
+                            "Pauli string {} does not act on {} qubits.",
+                            pauli, self.n_qubits
+                        ));
+                    }
+                    check_cbit(*cbit)?;
+                }
+                CircuitInstruction::Conditional { condition, gate } => {
+                    check_qubits(gate)?;
+                    for &cbit in &condition.cbits {
+                        check_cbit(cbit)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes the circuit on |0...0> using the thread-local random number generator.
+    pub fn execute(&self) -> Result<ExecutionResult, String> {
+        self.execute_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Executes the circuit on |0...0>, drawing measurement randomness from `rng`.
+    pub fn execute_with_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<ExecutionResult, String> {
+        if self.n_qubits == 0 {
+            return Err("Number of qubits must be greater than zero.".to_string());
+        }
+        let mut state = StabilizerCHForm::new(self.n_qubits);
+        let classical_register = self.execute_on(&mut state, rng)?;
+        Ok(ExecutionResult {
+            state,
+            classical_register,
+        })
+    }
+
+    /// Executes the circuit on `state` in place and returns the classical register.
+    ///
+    /// All classical bits start as `false`.
+    pub fn execute_on<R: Rng + ?Sized>(
+        &self,
+        state: &mut StabilizerCHForm,
+        rng: &mut R,
+    ) -> Result<Vec<bool>, String> {
+        if state.n_qubits() != self.n_qubits {
+            return Err(format!(
+                "Circuit acts on {} qubits but the state has {}.",
+                self.n_qubits,
+                state.n_qubits()
+            ));
+        }
+        self.validate()?;
+
+        let mut record = vec![false; self.n_clbits];
+        for instruction in &self.instructions {
+            match instruction {
+                CircuitInstruction::Gate(gate) => state._apply_gate(gate),
+                CircuitInstruction::Measure { qarg, cbit } => {
+                    record[*cbit] = state._measure_with_rng(*qarg, rng);
+                }
+                CircuitInstruction::Reset(qarg) => state._reset_with_rng(*qarg, rng),
+                CircuitInstruction::MeasurePauli { pauli, cbit } => {
+                    record[*cbit] = state._measure_pauli_with_rng(pauli, rng);
+                }
+                CircuitInstruction::Conditional { condition, gate } => {
+                    if condition.is_satisfied(&record) {
+                        state._apply_gate(gate);
+                    }
+                }
+            }
+        }
+        Ok(record)
+    }
+}
+
+impl From<CliffordCircuit> for DynamicCircuit {
+    fn from(circuit: CliffordCircuit) -> Self {
+        DynamicCircuit {
+            n_qubits: circuit.n_qubits,
+            n_clbits: 0,
+            instructions: circuit
+                .gates
+                .into_iter()
+                .map(CircuitInstruction::Gate)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::api::qargs::check_qargs;
+use crate::api::representation::pauli_string::symplectic_rank;
+use crate::api::representation::{Pauli, PauliString};
+use ndarray::Array2;
+use num_complex::Complex64;
+
+/// Largest number of qubits accepted by `to_density_matrix`.
+pub(crate) const MAX_DENSITY_MATRIX_QUBITS: usize = 10;
+
+/// A mixed stabilizer state on n qubits, represented exactly by k independent
+/// commuting generators g_1..g_k:
+///
+///   ρ = 2^{-n} ∏_i (I + g_i) = 2^{-n} Σ_{g ∈ S} g,
+///
+/// the uniform mixture over the 2^{n-k} states stabilized by S = <g_1, ..., g_k>.
+/// A pure state has k = n and the maximally mixed state k = 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MixedStabilizerState {
+    n_qubits: usize,
+    generators: Vec<PauliString>,
+}
+
+impl MixedStabilizerState {
+    /// Creates the state stabilized by `generators`.
+    ///
+    /// # Returns
+    /// An error if a generator acts on a different number of qubits, or the
+    /// generators do not commute or are not independent.
+    pub fn new(n_qubits: usize, generators: Vec<PauliString>) -> Result<Self, String> {
+        for (i, g) in generators.iter().enumerate() {
+            if g.n_qubits() != n_qubits {
+                return Err(format!(
+                    "Generator {} acts on {} qubits, expected {}.",
+                    g,
+                    g.n_qubits(),
+                    n_qubits
+                ));
+            }
+            if let Some(h) = generators[..i].iter().find(|h| !h.commutes_with(g)) {
+                return Err(format!("Generators {} and {} anticommute.", h, g));
+            }
+        }
+        if symplectic_rank(&generators) != generators.len() {
+            return Err("Generators are not independent.".to_string());
+        }
+        Ok(Self {
+            n_qubits,
+            generators,
+        })
+    }
+
+    /// Returns the maximally mixed state I / 2^n.
+    pub fn maximally_mixed(n_qubits: usize) -> Self {
+        Self {
+            n_qubits,
+            generators: Vec::new(),
+        }
+    }
+
+    pub fn n_qubits(&self) -> usize {
+        self.n_qubits
+    }
+
+    pub fn generators(&self) -> &[PauliString] {
+        &self.generators
+    }
+
+    /// Returns whether the state is pure, i.e. has n generators.
+    pub fn is_pure(&self) -> bool {
+        self.generators.len() == self.n_qubits
+    }
+
+    /// Returns the reduced state on `keep`, where qubit `i` of the result is
+    /// qubit `keep[i]` of `self`.
+    ///
+    /// Tracing out the other qubits keeps exactly the elements of S acting as the
+    /// identity on them. Generators of that subgroup are found by Gaussian
+    /// elimination on the symplectic bits of the traced-out qubits.
+    ///
+    /// # Returns
+    /// An error if `keep` contains repeated or out-of-range qubits.
+    ///
+    /// Time complexity: O(k n^2) for k generators.
+    pub fn partial_trace(&self, keep: &[usize]) -> Result<Self, String> {
+        check_qargs(keep, self.n_qubits)?;
+        let n = self.n_qubits;
+        let traced: Vec<usize> = (0..n).filter(|q| !keep.contains(q)).collect();
+
+        let mut rows = self.generators.clone();
+        let mut pivot = 0;
+        for bit in traced.iter().flat_map(|&q| [q, n + q]) {
+            let Some(i) = (pivot..rows.len()).find(|&i| rows[i].symplectic_bit(bit)) else {
+                continue;
+            };
+            rows.swap(pivot, i);
+            for j in 0..rows.len() {
+                if j != pivot && rows[j].symplectic_bit(bit) {
+                    rows[j] = rows[j]
+                        .product(&rows[pivot])
+                        .expect("Stabilizer generators commute.");
+                }
+            }
+            pivot += 1;
+        }
+
+        let generators = rows[pivot..]
+            .iter()
+            .map(|g| PauliString::new(keep.iter().map(|&q| g.paulis[q]).collect(), g.negative))
+            .collect();
+        Ok(Self {
+            n_qubits: keep.len(),
+            generators,
+        })
+    }
+
+    /// Returns the dense 2^n x 2^n density matrix, with qubit 0 as the least
+    /// significant bit as in `StabilizerCHForm::to_statevector`.
+    ///
+    /// The 2^k elements of S are enumerated in Gray-code order, each adding 2^n
+    /// entries.
+    ///
+    /// # Returns
+    /// An error if the state has more than 10 qubits.
+    pub fn to_density_matrix(&self) -> Result<Array2<Complex64>, String> {
+        let n = self.n_qubits;
+        if n > MAX_DENSITY_MATRIX_QUBITS {
+            return Err(format!(
+                "to_density_matrix supports at most {} qubits, got {}.",
+                MAX_DENSITY_MATRIX_QUBITS, n
+            ));
+        }
+        let dim = 1usize << n;
+        let weight = 1.0 / dim as f64;
+        let mut rho = Array2::zeros((dim, dim));
+        let mut element = PauliString::identity(n);
+        for step in 0..1usize << self.generators.len() {
+            if step > 0 {
+                let flipped = step.trailing_zeros() as usize;
+                element = element
+                    .product(&self.generators[flipped])
+                    .expect("Stabilizer generators commute.");
+            }
+            add_pauli(&mut rho, &element, weight);
+        }
+        Ok(rho)
+    }
+}
+
+/// Adds `weight * pauli` to `matrix`, using P|i> = c_i |i ^ x_mask>.
+fn add_pauli(matrix: &mut Array2<Complex64>, pauli: &PauliString, weight: f64) {
+    let sign = if pauli.negative { -weight } else { weight };
+    let x_mask: usize = (0..pauli.n_qubits())
+        .filter(|&q| pauli.paulis[q].to_bits().0)
+        .map(|q| 1 << q)
+        .sum();
+    for i in 0..matrix.nrows() {
+        let mut coefficient = Complex64::new(sign, 0.0);
+        for (q, &p) in pauli.paulis.iter().enumerate() {
+            let bit_sign = if (i >> q) & 1 == 1 { -1.0 } else { 1.0 };
+            match p {
+                Pauli::I | Pauli::X => {}
+                // Y|b> = i (-1)^b |1 - b>
+                Pauli::Y => coefficient *= Complex64::new(0.0, bit_sign),
+                Pauli::Z => coefficient *= bit_sign,
+            }
+        }
+        matrix[[i ^ x_mask, i]] += coefficient;
+    }
+}
@@ -1,6 +1,20 @@
 pub mod clifford_gate;
 pub mod clifford_circuit;
+pub mod clifford_tableau;
+pub mod coupling_map;
+pub mod dynamic_circuit;
+pub mod mixed_stabilizer_state;
 pub mod parser;
+pub mod pauli_string;
+pub mod single_qubit_clifford;
 
 pub use clifford_gate::CliffordGate;
-pub use clifford_circuit::CliffordCircuit;
\ No newline at end of file
+pub use clifford_circuit::{
+    CliffordCircuit, CliffordCircuitBuilder, GateSet, OptimizationReport,
+};
+pub use clifford_tableau::CliffordTableau;
+pub use coupling_map::CouplingMap;
+pub use dynamic_circuit::{CircuitInstruction, ClassicalCondition, DynamicCircuit, ExecutionResult};
+pub use mixed_stabilizer_state::MixedStabilizerState;
+pub use pauli_string::{Pauli, PauliString};
+pub use single_qubit_clifford::SingleQubitClifford;
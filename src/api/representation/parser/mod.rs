@@ -1,15 +1,60 @@
-use crate::api::representation::{CliffordCircuit, CliffordGate};
+use crate::api::representation::{
+    CircuitInstruction, ClassicalCondition, CliffordCircuit, CliffordGate, DynamicCircuit,
+};
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+lazy_static::lazy_static! {
+    static ref QREG_RE: Regex = Regex::new(r"qreg\s+([a-zA-Z][a-zA-Z0-9_]*)\s*\[\s*(\d+)\s*\]\s*;").unwrap();
+    static ref CREG_RE: Regex = Regex::new(r"^creg\s+([a-zA-Z][a-zA-Z0-9_]*)\s*\[\s*(\d+)\s*\]\s*;").unwrap();
+    static ref MEASURE_RE: Regex = Regex::new(r"^measure\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*->\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+    static ref RESET_RE: Regex = Regex::new(r"^reset\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+    static ref IF_RE: Regex = Regex::new(r"^if\s*\(\s*([a-zA-Z][a-zA-Z0-9_]*)\s*==\s*(\d+)\s*\)\s*(.+)$").unwrap();
+    static ref GATE1_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+    static ref GATE2_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+
+    static ref SINGLE_QUBIT_GATES: HashMap<&'static str, fn(usize) -> CliffordGate> = {
+        let mut m = HashMap::new();
+        m.insert("h", CliffordGate::H as fn(usize) -> CliffordGate);
+        m.insert("x", CliffordGate::X as fn(usize) -> CliffordGate);
+        m.insert("y", CliffordGate::Y as fn(usize) -> CliffordGate);
+        m.insert("z", CliffordGate::Z as fn(usize) -> CliffordGate);
+        m.insert("s", CliffordGate::S as fn(usize) -> CliffordGate);
+        m.insert("sdg", CliffordGate::Sdg as fn(usize) -> CliffordGate);
+        m.insert("sx", CliffordGate::SqrtX as fn(usize) -> CliffordGate);
+        m.insert("sxdg", CliffordGate::SqrtXdg as fn(usize) -> CliffordGate);
+        m.insert("sy", CliffordGate::SqrtY as fn(usize) -> CliffordGate);
+        m.insert("sydg", CliffordGate::SqrtYdg as fn(usize) -> CliffordGate);
+        m.insert("h_xy", CliffordGate::HXY as fn(usize) -> CliffordGate);
+        m.insert("h_yz", CliffordGate::HYZ as fn(usize) -> CliffordGate);
+        m
+    };
+
+    static ref TWO_QUBIT_GATES: HashMap<&'static str, fn(usize, usize) -> CliffordGate> = {
+        let mut m = HashMap::new();
+        m.insert("cx", CliffordGate::CX as fn(usize, usize) -> CliffordGate);
+        m.insert("cy", CliffordGate::CY as fn(usize, usize) -> CliffordGate);
+        m.insert("cz", CliffordGate::CZ as fn(usize, usize) -> CliffordGate);
+        m.insert("swap", CliffordGate::Swap as fn(usize, usize) -> CliffordGate);
+        m.insert("iswap", CliffordGate::ISwap as fn(usize, usize) -> CliffordGate);
+        m.insert("iswapdg", CliffordGate::ISwapdg as fn(usize, usize) -> CliffordGate);
+        m.insert("ecr", CliffordGate::ECR as fn(usize, usize) -> CliffordGate);
+        m.insert("dcx", CliffordGate::DCX as fn(usize, usize) -> CliffordGate);
+        m
+    };
+}
+
 /// Parses an OpenQASM 2.0 string into a `CliffordCircuit`.
 ///
 /// This is a simplified parser that supports `qreg` declarations and
-/// a standard set of Clifford gates.
-/// It ignores comments, headers, and includes.
+/// a standard set of Clifford gates: `h`, `x`, `y`, `z`, `s`, `sdg`, `sx`, `sxdg`,
+/// `cx`, `cy`, `cz`, `swap`, `iswap`, `ecr` and `dcx` (with Qiskit's conventions),
+/// plus `sy`, `sydg`, `h_xy`, `h_yz` and `iswapdg`.
+/// It ignores comments, headers, includes and `creg` declarations.
 /// **Note:** `measure` operations are detected and ignored, with a warning printed to stderr.
+/// Use `from_qasm_str_dynamic` to keep measurements, resets and classical control.
 ///
 /// # Arguments
 /// * `qasm_str` - A string slice containing the OpenQASM 2.0 circuit description.
@@ -17,35 +62,98 @@ use std::path::Path;
 /// # Returns
 /// A `Result` containing the parsed `CliffordCircuit` or a `String` error message.
 pub fn from_qasm_str(qasm_str: &str) -> Result<CliffordCircuit, String> {
-    lazy_static::lazy_static! {
-        static ref QREG_RE: Regex = Regex::new(r"qreg\s+([a-zA-Z][a-zA-Z0-9_]*)\s*\[\s*(\d+)\s*\]\s*;").unwrap();
-        static ref GATE1_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
-        static ref GATE2_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
-
-        static ref SINGLE_QUBIT_GATES: HashMap<&'static str, fn(usize) -> CliffordGate> = {
-            let mut m = HashMap::new();
-            m.insert("h", CliffordGate::H as fn(usize) -> CliffordGate);
-            m.insert("x", CliffordGate::X as fn(usize) -> CliffordGate);
-            m.insert("y", CliffordGate::Y as fn(usize) -> CliffordGate);
-            m.insert("z", CliffordGate::Z as fn(usize) -> CliffordGate);
-            m.insert("s", CliffordGate::S as fn(usize) -> CliffordGate);
-            m.insert("sdg", CliffordGate::Sdg as fn(usize) -> CliffordGate);
-            m.insert("sx", CliffordGate::SqrtX as fn(usize) -> CliffordGate);
-            m.insert("sxdg", CliffordGate::SqrtXdg as fn(usize) -> CliffordGate);
-            m
-        };
-
-        static ref TWO_QUBIT_GATES: HashMap<&'static str, fn(usize, usize) -> CliffordGate> = {
-            let mut m = HashMap::new();
-            m.insert("cx", CliffordGate::CX as fn(usize, usize) -> CliffordGate);
-            m.insert("cz", CliffordGate::CZ as fn(usize, usize) -> CliffordGate);
-            m.insert("swap", CliffordGate::Swap as fn(usize, usize) -> CliffordGate);
-            m
-        };
+    let circuit = parse_qasm(qasm_str, false)?;
+    let gates = circuit
+        .instructions
+        .into_iter()
+        .map(|instruction| match instruction {
+            CircuitInstruction::Gate(gate) => gate,
+            _ => unreachable!("Non-unitary instructions are rejected while parsing."),
+        })
+        .collect();
+    Ok(CliffordCircuit {
+        n_qubits: circuit.n_qubits,
+        gates,
+    })
+}
+
+/// Parses an OpenQASM 2.0 string into a `DynamicCircuit`.
+///
+/// In addition to the gates supported by `from_qasm_str`, this accepts a single
+/// `creg` declaration, `measure q[i] -> c[j];`, `reset q[i];` and gates
+/// conditioned as `if(c==k) gate ...;`.
+///
+/// # Arguments
+/// * `qasm_str` - A string slice containing the OpenQASM 2.0 circuit description.
+///
+/// # Returns
+/// A `Result` containing the parsed `DynamicCircuit` or a `String` error message.
+pub fn from_qasm_str_dynamic(qasm_str: &str) -> Result<DynamicCircuit, String> {
+    parse_qasm(qasm_str, true)
+}
+
+/// Parses an OpenQASM 2.0 file into a `CliffordCircuit`.
+///
+/// # Arguments
+/// * `path` - A path to the QASM file.
+///
+/// # Returns
+/// A `Result` containing the parsed `CliffordCircuit` or a `String` error message.
+pub fn from_qasm_file<P: AsRef<Path>>(path: P) -> Result<CliffordCircuit, String> {
+    from_qasm_str(&read_qasm_file(path.as_ref())?)
+}
+
+/// Parses an OpenQASM 2.0 file into a `DynamicCircuit`.
+///
+/// # Arguments
+/// * `path` - A path to the QASM file.
+///
+/// # Returns
+/// A `Result` containing the parsed `DynamicCircuit` or a `String` error message.
+pub fn from_qasm_file_dynamic<P: AsRef<Path>>(path: P) -> Result<DynamicCircuit, String> {
+    from_qasm_str_dynamic(&read_qasm_file(path.as_ref())?)
+}
+
+fn read_qasm_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))
+}
+
+fn parse_index(s: &str, line: &str) -> Result<usize, String> {
+    s.parse::<usize>()
+        .map_err(|_| format!("Invalid index in line: {}", line))
+}
+
+/// Parses a single gate application such as `cx q[0], q[1];`.
+///
+/// Returns `Ok(None)` if the line is not a supported gate.
+fn parse_gate(line: &str) -> Result<Option<CliffordGate>, String> {
+    if let Some(caps) = GATE2_RE.captures(line)
+        && let Some(gate_fn) = TWO_QUBIT_GATES.get(&caps[1])
+    {
+        let q1 = parse_index(&caps[3], line)?;
+        let q2 = parse_index(&caps[5], line)?;
+        return Ok(Some(gate_fn(q1, q2)));
+    }
+
+    if let Some(caps) = GATE1_RE.captures(line)
+        && let Some(gate_fn) = SINGLE_QUBIT_GATES.get(&caps[1])
+    {
+        let qarg = parse_index(&caps[3], line)?;
+        return Ok(Some(gate_fn(qarg)));
     }
 
+    Ok(None)
+}
+
+/// Parses the QASM source into a `DynamicCircuit`.
+///
+/// If `dynamic` is false, `measure` is skipped with a warning and other
+/// non-unitary instructions are rejected.
+fn parse_qasm(qasm_str: &str, dynamic: bool) -> Result<DynamicCircuit, String> {
     let mut n_qubits: Option<usize> = None;
-    let mut gates = Vec::new();
+    let mut creg: Option<(String, usize)> = None;
+    let mut instructions = Vec::new();
 
     for (line_num, line_content) in qasm_str.lines().enumerate() {
         let line = line_content.trim();
@@ -68,62 +176,88 @@ pub fn from_qasm_str(qasm_str: &str) -> Result<CliffordCircuit, String> {
             continue;
         }
 
-        if line.starts_with("measure") {
-            eprintln!(
-                "[Warning] Line {}: `measure` operation is ignored by the parser.",
-                line_num + 1
-            );
+        if let Some(caps) = CREG_RE.captures(line) {
+            if creg.is_some() {
+                return Err("Multiple creg declarations are not supported.".to_string());
+            }
+            let size = caps[2]
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid creg size in line: {}", line))?;
+            creg = Some((caps[1].to_string(), size));
             continue;
         }
 
-        if let Some(caps) = GATE2_RE.captures(line) {
-            let gate_name = &caps[1];
-            if let Some(gate_fn) = TWO_QUBIT_GATES.get(gate_name) {
-                let q1 = caps[3]
-                    .parse::<usize>()
-                    .map_err(|_| format!("Invalid qubit index in line: {}", line))?;
-                let q2 = caps[5]
-                    .parse::<usize>()
-                    .map_err(|_| format!("Invalid qubit index in line: {}", line))?;
-                gates.push(gate_fn(q1, q2));
+        if line.starts_with("measure") {
+            if !dynamic {
+                eprintln!(
+                    "[Warning] Line {}: `measure` operation is ignored by the parser.",
+                    line_num + 1
+                );
                 continue;
             }
+            let caps = MEASURE_RE
+                .captures(line)
+                .ok_or_else(|| format!("Malformed measure in line: {}", line))?;
+            check_creg_name(&creg, &caps[3], line)?;
+            instructions.push(CircuitInstruction::Measure {
+                qarg: parse_index(&caps[2], line)?,
+                cbit: parse_index(&caps[4], line)?,
+            });
+            continue;
         }
-        
-        if let Some(caps) = GATE1_RE.captures(line) {
-            let gate_name = &caps[1];
-             if let Some(gate_fn) = SINGLE_QUBIT_GATES.get(gate_name) {
-                let qarg = caps[3]
-                    .parse::<usize>()
-                    .map_err(|_| format!("Invalid qubit index in line: {}", line))?;
-                gates.push(gate_fn(qarg));
+
+        if line.starts_with("reset") || line.starts_with("if") {
+            if !dynamic {
+                return Err(format!(
+                    "Non-unitary instruction in line: {} (use `from_qasm_str_dynamic`)",
+                    line
+                ));
+            }
+            if let Some(caps) = RESET_RE.captures(line) {
+                instructions.push(CircuitInstruction::Reset(parse_index(&caps[2], line)?));
+                continue;
+            }
+            if let Some(caps) = IF_RE.captures(line) {
+                let n_clbits = check_creg_name(&creg, &caps[1], line)?;
+                let value = caps[2]
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid condition value in line: {}", line))?;
+                let gate = parse_gate(&caps[3])?
+                    .ok_or_else(|| format!("Unsupported conditional gate in line: {}", line))?;
+                instructions.push(CircuitInstruction::Conditional {
+                    condition: ClassicalCondition {
+                        cbits: (0..n_clbits).collect(),
+                        value,
+                    },
+                    gate,
+                });
                 continue;
             }
         }
 
+        if let Some(gate) = parse_gate(line)? {
+            instructions.push(CircuitInstruction::Gate(gate));
+            continue;
+        }
+
         return Err(format!("Unrecognized or malformed line: {}", line));
     }
 
     if let Some(n) = n_qubits {
-        Ok(CliffordCircuit {
+        Ok(DynamicCircuit {
             n_qubits: n,
-            gates,
+            n_clbits: creg.map_or(0, |(_, size)| size),
+            instructions,
         })
     } else {
         Err("qreg declaration not found in QASM string.".to_string())
     }
 }
 
-/// Parses an OpenQASM 2.0 file into a `CliffordCircuit`.
-/// 
-/// # Arguments
-/// * `path` - A path to the QASM file.
-/// 
-/// # Returns
-/// A `Result` containing the parsed `CliffordCircuit` or a `String` error message.
-pub fn from_qasm_file<P: AsRef<Path>>(path: P) -> Result<CliffordCircuit, String> {
-    let qasm_content = fs::read_to_string(path.as_ref())
-        .map_err(|e| format!("Failed to read file '{}': {}", path.as_ref().display(), e))?;
-
-    from_qasm_str(&qasm_content)
-}
\ No newline at end of file
+/// Checks that `name` refers to the declared classical register and returns its size.
+fn check_creg_name(creg: &Option<(String, usize)>, name: &str, line: &str) -> Result<usize, String> {
+    match creg {
+        Some((creg_name, size)) if creg_name == name => Ok(*size),
+        _ => Err(format!("Undeclared classical register in line: {}", line)),
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A single-qubit Pauli operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pauli {
+    I,
+    X,
+    Y,
+    Z,
+}
+
+impl Pauli {
+    /// Returns the (x, z) bits of the symplectic representation, where Y = (1, 1).
+    pub fn to_bits(self) -> (bool, bool) {
+        match self {
+            Pauli::I => (false, false),
+            Pauli::X => (true, false),
+            Pauli::Y => (true, true),
+            Pauli::Z => (false, true),
+        }
+    }
+
+    /// Creates a Pauli operator from its (x, z) bits.
+    pub fn from_bits(x: bool, z: bool) -> Self {
+        match (x, z) {
+            (false, false) => Pauli::I,
+            (true, false) => Pauli::X,
+            (true, true) => Pauli::Y,
+            (false, true) => Pauli::Z,
+        }
+    }
+}
+
+/// A Hermitian Pauli product `±P_0 ⊗ P_1 ⊗ ... ⊗ P_{n-1}`.
+///
+/// `paulis[i]` acts on qubit `i`, so the string form `+XZ` means X on qubit 0
+/// and Z on qubit 1.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PauliString {
+    pub negative: bool,
+    pub paulis: Vec<Pauli>,
+}
+
+impl PauliString {
+    pub fn new(paulis: Vec<Pauli>, negative: bool) -> Self {
+        Self { negative, paulis }
+    }
+
+    /// Returns the identity on `n` qubits.
+    pub fn identity(n: usize) -> Self {
+        Self::new(vec![Pauli::I; n], false)
+    }
+
+    /// Returns `+P` acting on qubit `qarg` of an `n`-qubit register.
+    pub fn single(n: usize, qarg: usize, pauli: Pauli) -> Self {
+        let mut paulis = vec![Pauli::I; n];
+        paulis[qarg] = pauli;
+        Self::new(paulis, false)
+    }
+
+    pub fn n_qubits(&self) -> usize {
+        self.paulis.len()
+    }
+
+    /// Returns the indices of the qubits on which the operator acts non-trivially.
+    pub fn support(&self) -> Vec<usize> {
+        self.paulis
+            .iter()
+            .enumerate()
+            .filter(|&(_, &p)| p != Pauli::I)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns the number of non-identity factors.
+    pub fn weight(&self) -> usize {
+        self.paulis.iter().filter(|&&p| p != Pauli::I).count()
+    }
+
+    /// Returns whether `self` commutes with `other`.
+    pub fn commutes_with(&self, other: &PauliString) -> bool {
+        if self.n_qubits() != other.n_qubits() {
+            panic!("Pauli strings must act on the same number of qubits.");
+        }
+        let anticommuting = self
+            .paulis
+            .iter()
+            .zip(&other.paulis)
+            .filter(|&(&a, &b)| a != Pauli::I && b != Pauli::I && a != b)
+            .count();
+        anticommuting % 2 == 0
+    }
+
+    /// Returns the product `self * other` of two commuting operators.
+    ///
+    /// # Returns
+    /// An error if the operators act on different numbers of qubits or anticommute,
+    /// in which case the product is not Hermitian.
+    pub fn product(&self, other: &PauliString) -> Result<PauliString, String> {
+        if self.n_qubits() != other.n_qubits() {
+            return Err("Pauli strings must act on the same number of qubits.".to_string());
+        }
+        // Accumulates the power of i from the single-qubit products.
+        let mut i_power = 0;
+        let paulis = self
+            .paulis
+            .iter()
+            .zip(&other.paulis)
+            .map(|(&a, &b)| {
+                i_power += match (a, b) {
+                    (Pauli::X, Pauli::Y) | (Pauli::Y, Pauli::Z) | (Pauli::Z, Pauli::X) => 1,
+                    (Pauli::Y, Pauli::X) | (Pauli::Z, Pauli::Y) | (Pauli::X, Pauli::Z) => 3,
+                    _ => 0,
+                };
+                let ((ax, az), (bx, bz)) = (a.to_bits(), b.to_bits());
+                Pauli::from_bits(ax ^ bx, az ^ bz)
+            })
+            .collect();
+        if i_power % 2 == 1 {
+            return Err(format!("{} and {} anticommute.", self, other));
+        }
+        let negative = self.negative ^ other.negative ^ (i_power % 4 == 2);
+        Ok(Self::new(paulis, negative))
+    }
+
+    /// Returns whether bit `bit` of the symplectic vector is set, where bits
+    /// `0..n` are the X bits and `n..2n` the Z bits.
+    pub(crate) fn symplectic_bit(&self, bit: usize) -> bool {
+        let n = self.n_qubits();
+        let (x, z) = self.paulis[bit % n].to_bits();
+        if bit < n { x } else { z }
+    }
+}
+
+/// Returns the rank over GF(2) of the symplectic vectors of `paulis`, which must
+/// act on the same number of qubits.
+///
+/// Time complexity: O(k n min(k, n)) for k operators on n qubits.
+pub(crate) fn symplectic_rank(paulis: &[PauliString]) -> usize {
+    let mut rows: Vec<Vec<bool>> = paulis
+        .iter()
+        .map(|p| {
+            (0..2 * p.n_qubits())
+                .map(|bit| p.symplectic_bit(bit))
+                .collect()
+        })
+        .collect();
+    let n_bits = rows.first().map_or(0, |row| row.len());
+    let mut rank = 0;
+    for bit in 0..n_bits {
+        let Some(i) = (rank..rows.len()).find(|&i| rows[i][bit]) else {
+            continue;
+        };
+        rows.swap(rank, i);
+        let pivot = rows[rank].clone();
+        for row in rows[rank + 1..].iter_mut().filter(|row| row[bit]) {
+            row.iter_mut().zip(&pivot).for_each(|(a, &b)| *a ^= b);
+        }
+        rank += 1;
+    }
+    rank
+}
+
+impl fmt::Display for PauliString {
+    /// Formats the operator as e.g. `+XIZ` or `-YY`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.negative { '-' } else { '+' })?;
+        for p in &self.paulis {
+            let c = match p {
+                Pauli::I => 'I',
+                Pauli::X => 'X',
+                Pauli::Y => 'Y',
+                Pauli::Z => 'Z',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PauliString {
+    type Err = String;
+
+    /// Parses strings such as `XIZ`, `+XX` or `-Y_Z` (`_` is an alias for `I`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, body) = match s.trim().strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.trim().strip_prefix('+').unwrap_or(s.trim())),
+        };
+        let paulis = body
+            .chars()
+            .map(|c| match c {
+                'I' | '_' => Ok(Pauli::I),
+                'X' => Ok(Pauli::X),
+                'Y' => Ok(Pauli::Y),
+                'Z' => Ok(Pauli::Z),
+                _ => Err(format!("Invalid Pauli character '{}' in '{}'.", c, s)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(paulis, negative))
+    }
+}
@@ -0,0 +1,282 @@
+use crate::api::representation::{CliffordGate, Pauli};
+
+lazy_static::lazy_static! {
+    static ref TABLES: GroupTables = GroupTables::new();
+}
+
+/// An element of the 24-element single-qubit Clifford group (modulo global phase).
+///
+/// The element is stored as its conjugation action `P -> C P C†` on X and Z,
+/// each image being a signed Pauli operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SingleQubitClifford {
+    x_image: (Pauli, bool),
+    z_image: (Pauli, bool),
+}
+
+/// The canonical decomposition `X^x · S^s_power · H^hadamard · S^s_before`.
+///
+/// Every element has exactly one such decomposition with `s_before` only
+/// set when `hadamard` is, so at most one Hadamard is ever needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CanonicalForm {
+    pub s_before: bool,
+    pub hadamard: bool,
+    pub s_power: u8,
+    pub x: bool,
+}
+
+impl CanonicalForm {
+    fn all() -> impl Iterator<Item = CanonicalForm> {
+        [(false, false), (false, true), (true, true)]
+            .into_iter()
+            .flat_map(|(s_before, hadamard)| {
+                (0..4).flat_map(move |s_power| {
+                    [false, true].into_iter().map(move |x| CanonicalForm {
+                        s_before,
+                        hadamard,
+                        s_power,
+                        x,
+                    })
+                })
+            })
+    }
+
+    /// Returns the gates of the decomposition in application order.
+    fn to_gates(self, qarg: usize) -> Vec<CliffordGate> {
+        let mut gates = Vec::new();
+        if self.s_before {
+            gates.push(CliffordGate::S(qarg));
+        }
+        if self.hadamard {
+            gates.push(CliffordGate::H(qarg));
+        }
+        match self.s_power {
+            1 => gates.push(CliffordGate::S(qarg)),
+            2 => gates.push(CliffordGate::Z(qarg)),
+            3 => gates.push(CliffordGate::Sdg(qarg)),
+            _ => {}
+        }
+        if self.x {
+            gates.push(CliffordGate::X(qarg));
+        }
+        gates
+    }
+}
+
+impl SingleQubitClifford {
+    pub const IDENTITY: Self = Self::from_images((Pauli::X, false), (Pauli::Z, false));
+
+    /// Creates the element mapping X to `x_image` and Z to `z_image` under conjugation.
+    ///
+    /// Images are given as `(pauli, negative)`.
+    const fn from_images(x_image: (Pauli, bool), z_image: (Pauli, bool)) -> Self {
+        Self { x_image, z_image }
+    }
+
+    /// Creates an element from the images of X and Z, given as `(pauli, negative)`.
+    ///
+    /// # Returns
+    /// An error if the images do not anticommute or are the identity.
+    pub fn from_conjugation(
+        x_image: (Pauli, bool),
+        z_image: (Pauli, bool),
+    ) -> Result<Self, String> {
+        if x_image.0 == Pauli::I || z_image.0 == Pauli::I || x_image.0 == z_image.0 {
+            return Err(format!(
+                "Images {:?} and {:?} do not define a Clifford operation.",
+                x_image, z_image
+            ));
+        }
+        Ok(Self::from_images(x_image, z_image))
+    }
+
+    /// Returns all 24 elements of the group.
+    pub fn all() -> Vec<Self> {
+        TABLES.elements.clone()
+    }
+
+    /// Returns the position of `self` in `all()`.
+    fn index(&self) -> usize {
+        TABLES.positions[self.key()]
+    }
+
+    /// Returns a key in `0..36` determined by the images of X and Z.
+    fn key(&self) -> usize {
+        image_key(self.x_image) * 6 + image_key(self.z_image)
+    }
+
+    /// Returns the element implemented by a single-qubit gate, or `None` for
+    /// multi-qubit gates.
+    pub fn from_gate(gate: &CliffordGate) -> Option<Self> {
+        use Pauli::{X, Y, Z};
+        let (x_image, z_image) = match gate {
+            CliffordGate::H(_) => ((Z, false), (X, false)),
+            CliffordGate::X(_) => ((X, false), (Z, true)),
+            CliffordGate::Y(_) => ((X, true), (Z, true)),
+            CliffordGate::Z(_) => ((X, true), (Z, false)),
+            CliffordGate::S(_) => ((Y, false), (Z, false)),
+            CliffordGate::Sdg(_) => ((Y, true), (Z, false)),
+            CliffordGate::SqrtX(_) => ((X, false), (Y, true)),
+            CliffordGate::SqrtXdg(_) => ((X, false), (Y, false)),
+            CliffordGate::SqrtY(_) => ((Z, true), (X, false)),
+            CliffordGate::SqrtYdg(_) => ((Z, false), (X, true)),
+            CliffordGate::HXY(_) => ((Y, false), (Z, true)),
+            CliffordGate::HYZ(_) => ((X, true), (Y, false)),
+            _ => return None,
+        };
+        Some(Self::from_images(x_image, z_image))
+    }
+
+    /// Returns the element implemented by a sequence of single-qubit gates,
+    /// applied in order.
+    ///
+    /// # Returns
+    /// An error if a gate acts on more than one qubit or the gates act on
+    /// different qubits.
+    pub fn from_gates(gates: &[CliffordGate]) -> Result<Self, String> {
+        let mut qarg = None;
+        let mut result = Self::IDENTITY;
+        for gate in gates {
+            let element = Self::from_gate(gate)
+                .ok_or_else(|| format!("{:?} is not a single-qubit gate.", gate))?;
+            let q = gate.qubits()[0];
+            if *qarg.get_or_insert(q) != q {
+                return Err("All gates must act on the same qubit.".to_string());
+            }
+            result = result.compose(&element);
+        }
+        Ok(result)
+    }
+
+    /// Returns a gate sequence on `qarg` implementing this element, using at most
+    /// one H together with S, Sdg, Z and X.
+    pub fn to_gates(&self, qarg: usize) -> Vec<CliffordGate> {
+        self.canonical_form().to_gates(qarg)
+    }
+
+    pub(crate) fn canonical_form(&self) -> CanonicalForm {
+        TABLES.canonical_forms[self.index()]
+    }
+
+    /// Returns `C P C†` as `(pauli, negative)`.
+    pub fn conjugate(&self, pauli: Pauli) -> (Pauli, bool) {
+        match pauli {
+            Pauli::I => (Pauli::I, false),
+            Pauli::X => self.x_image,
+            Pauli::Z => self.z_image,
+            Pauli::Y => {
+                // Y = iXZ, so C Y C† = i (C X C†)(C Z C†).
+                let (x_pauli, x_negative) = self.x_image;
+                let (z_pauli, z_negative) = self.z_image;
+                let (product, i_power) = multiply_paulis(x_pauli, z_pauli);
+                let negative = (1 + i_power) % 4 == 2;
+                (product, negative ^ x_negative ^ z_negative)
+            }
+        }
+    }
+
+    /// Returns the element that applies `self` first and then `other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        TABLES.elements[TABLES.products[self.index()][other.index()]]
+    }
+
+    /// Computes `compose` from the conjugation images, used to build the tables.
+    fn compose_images(&self, other: &Self) -> Self {
+        let image = |(pauli, negative): (Pauli, bool)| {
+            let (image, image_negative) = other.conjugate(pauli);
+            (image, negative ^ image_negative)
+        };
+        Self::from_images(image(self.x_image), image(self.z_image))
+    }
+
+    pub fn inverse(&self) -> Self {
+        TABLES.elements[TABLES.inverses[self.index()]]
+    }
+}
+
+/// Lookup tables for the 24 group elements, which are listed in the order of
+/// `CanonicalForm::all` and referred to by their position in that list.
+struct GroupTables {
+    elements: Vec<SingleQubitClifford>,
+    /// Position of each element, indexed by `SingleQubitClifford::key`.
+    positions: [usize; 36],
+    canonical_forms: Vec<CanonicalForm>,
+    inverses: Vec<usize>,
+    /// `products[i][j]` is the position of element i followed by element j.
+    products: Vec<Vec<usize>>,
+}
+
+impl GroupTables {
+    fn new() -> Self {
+        let canonical_forms: Vec<CanonicalForm> = CanonicalForm::all().collect();
+        let elements: Vec<SingleQubitClifford> = canonical_forms
+            .iter()
+            .map(|form| {
+                form.to_gates(0)
+                    .iter()
+                    .map(|gate| SingleQubitClifford::from_gate(gate).unwrap())
+                    .fold(SingleQubitClifford::IDENTITY, |acc, element| {
+                        acc.compose_images(&element)
+                    })
+            })
+            .collect();
+
+        let mut positions = [usize::MAX; 36];
+        for (i, element) in elements.iter().enumerate() {
+            positions[element.key()] = i;
+        }
+        let position = |element: SingleQubitClifford| positions[element.key()];
+
+        let products: Vec<Vec<usize>> = elements
+            .iter()
+            .map(|a| {
+                elements
+                    .iter()
+                    .map(|b| position(a.compose_images(b)))
+                    .collect()
+            })
+            .collect();
+        let identity = position(SingleQubitClifford::IDENTITY);
+        let inverses = products
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .position(|&product| product == identity)
+                    .expect("Every group element has an inverse.")
+            })
+            .collect();
+
+        Self {
+            elements,
+            positions,
+            canonical_forms,
+            inverses,
+            products,
+        }
+    }
+}
+
+/// Returns a key in `0..6` for an image `(pauli, negative)` with `pauli != I`.
+fn image_key((pauli, negative): (Pauli, bool)) -> usize {
+    let pauli = match pauli {
+        Pauli::X => 0,
+        Pauli::Y => 1,
+        Pauli::Z => 2,
+        Pauli::I => unreachable!("Images of X and Z are never the identity."),
+    };
+    2 * pauli + negative as usize
+}
+
+/// Returns `(P, k)` such that `a b = i^k P`.
+fn multiply_paulis(a: Pauli, b: Pauli) -> (Pauli, u8) {
+    let (ax, az) = a.to_bits();
+    let (bx, bz) = b.to_bits();
+    let product = Pauli::from_bits(ax ^ bx, az ^ bz);
+    let i_power = match (a, b) {
+        (Pauli::X, Pauli::Y) | (Pauli::Y, Pauli::Z) | (Pauli::Z, Pauli::X) => 1,
+        (Pauli::Y, Pauli::X) | (Pauli::Z, Pauli::Y) | (Pauli::X, Pauli::Z) => 3,
+        _ => 0,
+    };
+    (product, i_power)
+}
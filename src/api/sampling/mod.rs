@@ -0,0 +1,3 @@
+pub mod pauli_frame;
+
+pub use pauli_frame::{NoiseModel, PauliChannel, PauliFrameSimulator};
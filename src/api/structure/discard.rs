@@ -1,8 +1,99 @@
 use crate::StabilizerCHForm;
+use rand::Rng;
+use std::fmt;
 
+/// Reasons why qubits cannot be discarded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardError {
+    /// The qubit index is not below the number of qubits.
+    QubitOutOfBounds { qarg: usize, n_qubits: usize },
+    /// The same qubit was given more than once.
+    RepeatedQubit(usize),
+    /// The qubit is not deterministically |0> or |1>, e.g. it is in superposition
+    /// or entangled with other qubits.
+    NotInBasisState(usize),
+    /// Discarding would leave a state without qubits.
+    NoQubitsLeft,
+}
+
+impl fmt::Display for DiscardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiscardError::QubitOutOfBounds { qarg, n_qubits } => write!(
+                f,
+                "Qubit index {} out of bounds for a state on {} qubits.",
+                qarg, n_qubits
+            ),
+            DiscardError::RepeatedQubit(qarg) => {
+                write!(f, "Qubit index {} appears more than once.", qarg)
+            }
+            DiscardError::NotInBasisState(qarg) => write!(
+                f,
+                "Qubit {} is not deterministically |0> or |1>; measure or reset it first.",
+                qarg
+            ),
+            DiscardError::NoQubitsLeft => write!(f, "Cannot discard every qubit of a state."),
+        }
+    }
+}
+
+impl std::error::Error for DiscardError {}
 
 impl StabilizerCHForm {
-    pub fn discard(&mut self, qarg: usize) -> Result<(), &'static str> {
+    /// Removes the qubit at index `qarg`, which must be deterministically |0> or |1>
+    /// (and hence disentangled from the rest). Qubits after `qarg` shift down by one.
+    ///
+    /// # Returns
+    /// An error, leaving the state unchanged, if `qarg` is out of bounds, is the
+    /// only qubit, or is not in a computational basis state.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn discard(&mut self, qarg: usize) -> Result<(), DiscardError> {
         self._discard(qarg)
     }
-}
\ No newline at end of file
+
+    /// Removes the qubits `qargs` in one pass. Each must satisfy the precondition
+    /// of `discard`; the remaining qubits keep their relative order.
+    ///
+    /// # Returns
+    /// An error, leaving the state unchanged, if a qubit is out of bounds, repeated
+    /// or not in a computational basis state, or no qubit would remain.
+    ///
+    /// Time complexity: O(k n^2) for k discarded qubits.
+    pub fn discard_many(&mut self, qargs: &[usize]) -> Result<(), DiscardError> {
+        self._discard_many(qargs)
+    }
+
+    /// Returns a copy of the state with the qubit at index `qarg` discarded.
+    ///
+    /// See `discard` for the precondition.
+    pub fn discarded(&self, qarg: usize) -> Result<StabilizerCHForm, DiscardError> {
+        let mut self_clone = self.clone();
+        self_clone.discard(qarg)?;
+        Ok(self_clone)
+    }
+
+    /// Measures the qubit at index `qarg` in the Z basis and removes it from the state.
+    ///
+    /// Unlike `discard`, the qubit may be in any state, including entangled with
+    /// the rest; the remaining qubits are left in the post-measurement state.
+    /// Qubits after `qarg` shift down by one.
+    ///
+    /// # Returns
+    /// The measurement outcome, or an error, leaving the state unchanged, if
+    /// `qarg` is out of bounds or the only qubit.
+    ///
+    /// Time complexity: O(n^2)
+    pub fn discard_measured(&mut self, qarg: usize) -> Result<bool, DiscardError> {
+        self._discard_measured_with_rng(qarg, &mut rand::thread_rng())
+    }
+
+    /// Same as `discard_measured`, drawing randomness from `rng`.
+    pub fn discard_measured_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        qarg: usize,
+        rng: &mut R,
+    ) -> Result<bool, DiscardError> {
+        self._discard_measured_with_rng(qarg, rng)
+    }
+}
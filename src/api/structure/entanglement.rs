@@ -0,0 +1,59 @@
+use crate::StabilizerCHForm;
+use crate::api::qargs::check_qargs;
+
+impl StabilizerCHForm {
+    /// Returns the entanglement entropy in bits between the qubits of `subsystem_a`
+    /// and the rest, computed from the GF(2) rank of the stabilizer generators.
+    ///
+    /// The entropy of a stabilizer state is always an integer, at most
+    /// min(|A|, n - |A|). It equals |A| minus the number of generators of
+    /// `reduced_density_matrix(subsystem_a)`.
+    ///
+    /// # Returns
+    /// An error if `subsystem_a` contains repeated or out-of-range qubits.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn entanglement_entropy(&self, subsystem_a: &[usize]) -> Result<usize, String> {
+        check_qargs(subsystem_a, self.n_qubits())?;
+        Ok(self._entanglement_entropy(subsystem_a))
+    }
+
+    /// Returns the Schmidt rank 2^S(A) across the bipartition `subsystem_a` vs. the rest.
+    ///
+    /// # Returns
+    /// An error if `subsystem_a` contains repeated or out-of-range qubits, or the
+    /// rank does not fit in `usize`.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn schmidt_rank(&self, subsystem_a: &[usize]) -> Result<usize, String> {
+        let entropy = self.entanglement_entropy(subsystem_a)?;
+        1usize
+            .checked_shl(entropy as u32)
+            .ok_or_else(|| format!("Schmidt rank 2^{} does not fit in usize.", entropy))
+    }
+
+    /// Returns the mutual information I(A:B) = S(A) + S(B) - S(AB) in bits
+    /// between the disjoint subsystems `subsystem_a` and `subsystem_b`.
+    ///
+    /// # Returns
+    /// An error if a subsystem contains repeated or out-of-range qubits, or the
+    /// subsystems overlap.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn mutual_information(
+        &self,
+        subsystem_a: &[usize],
+        subsystem_b: &[usize],
+    ) -> Result<usize, String> {
+        check_qargs(subsystem_a, self.n_qubits())?;
+        check_qargs(subsystem_b, self.n_qubits())?;
+        if let Some(q) = subsystem_b.iter().find(|q| subsystem_a.contains(q)) {
+            return Err(format!("Qubit index {} is in both subsystems.", q));
+        }
+        let union: Vec<usize> = subsystem_a.iter().chain(subsystem_b).copied().collect();
+        Ok(
+            self._entanglement_entropy(subsystem_a) + self._entanglement_entropy(subsystem_b)
+                - self._entanglement_entropy(&union),
+        )
+    }
+}
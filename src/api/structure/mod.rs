@@ -1,3 +1,7 @@
 pub mod discard;
+pub mod entanglement;
 pub mod kron;
 pub mod permute;
+pub mod stabilizers;
+
+pub use discard::DiscardError;
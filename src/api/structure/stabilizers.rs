@@ -0,0 +1,13 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::PauliString;
+
+impl StabilizerCHForm {
+    /// Returns n independent signed Pauli operators stabilizing the state.
+    ///
+    /// The generators are not canonicalized; they are read off the CH-form.
+    ///
+    /// Time complexity: O(n^3)
+    pub fn stabilizers(&self) -> Vec<PauliString> {
+        self._stabilizers()
+    }
+}
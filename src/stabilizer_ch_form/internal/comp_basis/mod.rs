@@ -1,5 +1,6 @@
 use crate::StabilizerCHForm;
-use crate::stabilizer_ch_form::internal::types::Scalar;
+use crate::stabilizer_ch_form::internal::types::{PhaseFactor, Scalar};
+use ndarray::Array1;
 
 impl StabilizerCHForm {
     /// Computes the amplitude <0...0|φ> for the stabilizer state φ.
@@ -38,4 +39,57 @@ impl StabilizerCHForm {
 
         ch_form_clone._amplitude_at_zero()
     }
+
+    /// Returns the 2^|v| basis states with non-zero amplitude and their amplitudes,
+    /// ordered by basis index (qubit 0 is the least significant bit).
+    ///
+    /// U_H|s> = 2^{-|v|/2} Σ_t (-1)^{s·t} |t>, summing over the bitstrings t that
+    /// agree with s where v_j = 0. U_C maps |t> to a multiple of |x> with x = G t,
+    /// since U_C† Z_p U_C = Z(G_p). The multiple is the conjugate of <t|U_C†|x>,
+    /// obtained by applying U_C† X_p U_C = i^{γ_p} X(F_p) Z(M_p) for each set bit
+    /// of x to U_C†|0> = |0>.
+    ///
+    /// NOTE: The amplitudes include the phase factor, but not ω.
+    ///
+    /// Time complexity: O(2^|v| n^2)
+    pub(crate) fn _nonzero_amplitudes(&self) -> Vec<(Array1<bool>, Scalar)> {
+        let n = self.n;
+        let free: Vec<usize> = (0..n).filter(|&j| self.vec_v[j]).collect();
+        let mut terms: Vec<(Array1<bool>, Scalar)> = (0..1usize << free.len())
+            .map(|k| {
+                let mut t = self.vec_s.clone();
+                let mut phase = self.phase_factor;
+                for (bit, &j) in free.iter().enumerate() {
+                    t[j] = (k >> bit) & 1 == 1;
+                    if t[j] && self.vec_s[j] {
+                        phase.flip_sign();
+                    }
+                }
+                let x: Array1<bool> = (0..n)
+                    .map(|p| (0..n).filter(|&j| self.mat_g[[p, j]] && t[j]).count() % 2 == 1)
+                    .collect();
+
+                let mut b = Array1::from_elem(n, false);
+                let mut conjugated = PhaseFactor::PLUS_ONE;
+                for p in (0..n).filter(|&p| x[p]) {
+                    if (0..n).filter(|&j| self.mat_m[[p, j]] && b[j]).count() % 2 == 1 {
+                        conjugated.flip_sign();
+                    }
+                    conjugated *= self.gamma[p];
+                    for j in 0..n {
+                        b[j] ^= self.mat_f[[p, j]];
+                    }
+                }
+                debug_assert_eq!(b, t);
+
+                let amplitude = Scalar::NonZero {
+                    phase: phase * conjugated.conjugated(),
+                    r: free.len(),
+                };
+                (x, amplitude)
+            })
+            .collect();
+        terms.sort_unstable_by(|(a, _), (b, _)| a.iter().rev().cmp(b.iter().rev()));
+        terms
+    }
 }
@@ -0,0 +1,20 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::CliffordTableau;
+
+impl StabilizerCHForm {
+    /// Applies `tableau` to the qubits `qargs`, where tableau qubit `i` acts on `qargs[i]`.
+    ///
+    /// The tableau is synthesized into elementary gates which are applied in turn,
+    /// so the global phase is that of the synthesized circuit.
+    ///
+    /// Time complexity: O(n_t^3 + n_t^2 n^2) where n_t is the size of the tableau.
+    pub(crate) fn _left_multiply_clifford_tableau(
+        &mut self,
+        tableau: &CliffordTableau,
+        qargs: &[usize],
+    ) {
+        for gate in &tableau.to_circuit().gates {
+            self._apply_gate(&gate.map_qubits(|q| qargs[q]));
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::stabilizer_ch_form::{PhaseFactor, StabilizerCHForm};
+
+impl StabilizerCHForm {
+    /// Applies the controlled-Y gate.
+    ///
+    /// CY maps Z_t → Z_c Z_t, X_c → X_c Y_t and X_t → Z_c X_t, so with
+    /// Y_t = i X_t Z_t the rows are updated as
+    /// G_t ⊕= G_c, F_c ⊕= F_t, M_c ⊕= M_t ⊕ G_t, M_t ⊕= G_c and
+    /// γ_c ← γ_c γ_t i (-1)^{M_c·F_t}.
+    ///
+    /// Time complexity: O(n)
+    pub(crate) fn _left_multiply_cy(&mut self, control: usize, target: usize) {
+        if control >= self.n || target >= self.n {
+            panic!("Qubit index out of bounds.");
+        }
+        if control == target {
+            return;
+        }
+
+        // 1. Update gamma (must be done before matrix updates)
+        let dot_product_is_one = self
+            .mat_m
+            .row(control)
+            .iter()
+            .zip(self.mat_f.row(target).iter())
+            .fold(false, |acc, (&m, &f)| acc ^ (m & f));
+        let mut gamma_c = self.gamma[control] * self.gamma[target] * PhaseFactor::PLUS_I;
+        if dot_product_is_one {
+            gamma_c.flip_sign();
+        }
+        self.gamma[control] = gamma_c;
+
+        // 2. Update matrices
+        let g_control_row = self.mat_g.row(control).to_owned();
+        let g_target_row = self.mat_g.row(target).to_owned();
+        let f_target_row = self.mat_f.row(target).to_owned();
+        let m_target_row = self.mat_m.row(target).to_owned();
+
+        let mut m_control_row = self.mat_m.row_mut(control);
+        m_control_row ^= &m_target_row;
+        m_control_row ^= &g_target_row;
+
+        let mut m_target_row = self.mat_m.row_mut(target);
+        m_target_row ^= &g_control_row;
+
+        let mut f_control_row = self.mat_f.row_mut(control);
+        f_control_row ^= &f_target_row;
+
+        let mut g_target_row = self.mat_g.row_mut(target);
+        g_target_row ^= &g_control_row;
+    }
+}
@@ -0,0 +1,22 @@
+use crate::StabilizerCHForm;
+
+impl StabilizerCHForm {
+    /// Applies the echoed cross-resonance gate ECR = (X ⊗ I - Y ⊗ X)/√2,
+    /// where the first factor acts on `qarg1`.
+    ///
+    /// ECR maps Z_2 → Z_1 Y_2, which is not a product of Z operators, so it is
+    /// not a C-type gate and needs one Hadamard update. Using
+    /// ECR = X_1 exp(-iπ/4 Z_1 X_2) and H S H = e^{iπ/4} S† H S†, it is applied as
+    /// ECR = X_1 CX(1, 2) (S ⊗ S†) H_2 S†_2, where everything but H_2 uses the
+    /// O(n) C-type rules.
+    ///
+    /// Time complexity: O(n^2)
+    pub(crate) fn _left_multiply_ecr(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_sdg(qarg2);
+        self._left_multiply_h(qarg2);
+        self._left_multiply_sdg(qarg2);
+        self._left_multiply_s(qarg1);
+        self._left_multiply_cx(qarg1, qarg2);
+        self._left_multiply_x(qarg1);
+    }
+}
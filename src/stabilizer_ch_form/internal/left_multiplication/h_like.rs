@@ -0,0 +1,23 @@
+use crate::{StabilizerCHForm, stabilizer_ch_form::internal::types::PhaseFactor};
+
+impl StabilizerCHForm {
+    /// Applies the Hadamard-like gate H_XY = (X + Y)/√2 = e^{iπ/4} X S†,
+    /// which swaps X and Y.
+    ///
+    /// Time complexity: O(n)
+    pub(crate) fn _left_multiply_h_xy(&mut self, qarg: usize) {
+        self._left_multiply_sdg(qarg);
+        self._left_multiply_x(qarg);
+        self.phase_factor *= PhaseFactor::EXP_I_PI_4;
+    }
+
+    /// Applies the Hadamard-like gate H_YZ = (Y + Z)/√2 = S H S†,
+    /// which swaps Y and Z.
+    ///
+    /// Time complexity: O(n^2)
+    pub(crate) fn _left_multiply_h_yz(&mut self, qarg: usize) {
+        self._left_multiply_sdg(qarg);
+        self._left_multiply_h(qarg);
+        self._left_multiply_s(qarg);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::StabilizerCHForm;
+
+impl StabilizerCHForm {
+    /// Applies the iSWAP gate using iSWAP = (S ⊗ S) SWAP CZ.
+    ///
+    /// Time complexity: O(n)
+    pub(crate) fn _left_multiply_iswap(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_cz(qarg1, qarg2);
+        self._left_multiply_swap(qarg1, qarg2);
+        self._left_multiply_s(qarg1);
+        self._left_multiply_s(qarg2);
+    }
+
+    /// Applies the adjoint of the iSWAP gate using iSWAP† = CZ SWAP (S† ⊗ S†).
+    ///
+    /// Time complexity: O(n)
+    pub(crate) fn _left_multiply_iswapdg(&mut self, qarg1: usize, qarg2: usize) {
+        self._left_multiply_sdg(qarg1);
+        self._left_multiply_sdg(qarg2);
+        self._left_multiply_swap(qarg1, qarg2);
+        self._left_multiply_cz(qarg1, qarg2);
+    }
+}
@@ -1,9 +1,18 @@
+pub mod clifford_tableau;
 pub mod cx;
+pub mod cy;
 pub mod cz;
+pub mod dcx;
+pub mod ecr;
 pub mod h;
+pub mod h_like;
+pub mod iswap;
+pub mod pauli_product;
 pub mod s;
+pub mod single_qubit_clifford;
 pub mod x;
 pub mod z;
 pub mod y;
 pub mod sqrt_x;
+pub mod sqrt_y;
 pub mod swap;
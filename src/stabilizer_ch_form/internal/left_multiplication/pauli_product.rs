@@ -0,0 +1,49 @@
+use crate::api::representation::{Pauli, PauliString};
+use crate::stabilizer_ch_form::{StabilizerCHForm, internal::types::PhaseFactor};
+
+impl StabilizerCHForm {
+    /// Applies the Pauli product `pauli`, including its sign.
+    ///
+    /// Time complexity: O(w n) where w is the weight of `pauli`.
+    pub(crate) fn _left_multiply_pauli(&mut self, pauli: &PauliString) {
+        if pauli.n_qubits() != self.n {
+            panic!("Pauli string length must be equal to the number of qubits.");
+        }
+        for (q, &p) in pauli.paulis.iter().enumerate() {
+            match p {
+                Pauli::I => {}
+                Pauli::X => self._left_multiply_x(q),
+                Pauli::Y => self._left_multiply_y(q),
+                Pauli::Z => self._left_multiply_z(q),
+            }
+        }
+        if pauli.negative {
+            self.phase_factor.flip_sign();
+        }
+    }
+
+    /// Applies exp(iπ/4 P), or exp(-iπ/4 P) if `negative` is set.
+    ///
+    /// P is rotated onto a single Z with `_rotate_pauli_to_z`, where
+    /// exp(iπ/4 Z) = e^{iπ/4} Sdg and exp(-iπ/4 Z) = e^{-iπ/4} S, and rotated back.
+    ///
+    /// Time complexity: O(w n^2) where w is the weight of `pauli`.
+    pub(crate) fn _left_multiply_pauli_rotation(&mut self, pauli: &PauliString, negative: bool) {
+        // The sign of P flips the direction of the rotation.
+        let negative = negative ^ pauli.negative;
+        // For P = ±I the rotation is only a global phase.
+        if let Some(pivot) = self._rotate_pauli_to_z(pauli) {
+            if negative {
+                self._left_multiply_s(pivot);
+            } else {
+                self._left_multiply_sdg(pivot);
+            }
+            self._unrotate_pauli_from_z(pauli);
+        }
+        self.phase_factor *= if negative {
+            PhaseFactor::EXP_I_7PI_4
+        } else {
+            PhaseFactor::EXP_I_PI_4
+        };
+    }
+}
@@ -0,0 +1,40 @@
+use crate::api::representation::SingleQubitClifford;
+use crate::stabilizer_ch_form::{StabilizerCHForm, internal::types::PhaseFactor};
+
+impl StabilizerCHForm {
+    /// Applies an arbitrary single-qubit Clifford to the qubit at index `qarg`.
+    ///
+    /// The element is applied through its canonical form `X^x S^k H^b S^c`, so the
+    /// S powers only touch M and gamma and at most one superposition is resolved.
+    /// The global phase is the one of that decomposition.
+    ///
+    /// Time complexity: O(n^2) if the decomposition contains H, O(n) otherwise
+    pub(crate) fn _left_multiply_single_qubit_clifford(
+        &mut self,
+        qarg: usize,
+        clifford: &SingleQubitClifford,
+    ) {
+        if qarg >= self.n {
+            panic!("Qubit index out of bounds.");
+        }
+        let form = clifford.canonical_form();
+        if form.hadamard {
+            self._left_multiply_s_power(qarg, form.s_before as u8);
+            self._left_multiply_h(qarg);
+        }
+        self._left_multiply_s_power(qarg, form.s_power);
+        if form.x {
+            self._left_multiply_x(qarg);
+        }
+    }
+
+    /// Applies S^k in a single update of M and gamma.
+    fn _left_multiply_s_power(&mut self, qarg: usize, k: u8) {
+        if k % 2 == 1 {
+            let g_row = self.mat_g.row(qarg).to_owned();
+            let mut m_row = self.mat_m.row_mut(qarg);
+            m_row ^= &g_row;
+        }
+        self.gamma[qarg] *= PhaseFactor::new(PhaseFactor::MINUS_I.to_int() * (k % 4));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{StabilizerCHForm, stabilizer_ch_form::internal::types::PhaseFactor};
+
+impl StabilizerCHForm {
+    /// Applies √Y = (1+i)/2 [[1, -1], [1, 1]] = e^{iπ/4} H Z.
+    ///
+    /// Time complexity: O(n^2)
+    pub(crate) fn _left_multiply_sqrt_y(&mut self, qarg: usize) {
+        self._left_multiply_z(qarg);
+        self._left_multiply_h(qarg);
+        self.phase_factor *= PhaseFactor::EXP_I_PI_4;
+    }
+
+    /// Applies √Y† = e^{-iπ/4} Z H.
+    ///
+    /// Time complexity: O(n^2)
+    pub(crate) fn _left_multiply_sqrt_ydg(&mut self, qarg: usize) {
+        self._left_multiply_h(qarg);
+        self._left_multiply_z(qarg);
+        self.phase_factor *= PhaseFactor::EXP_I_7PI_4;
+    }
+}
@@ -1,12 +1,24 @@
 use crate::StabilizerCHForm;
 
 impl StabilizerCHForm {
+    /// Applies the SWAP gate.
+    ///
+    /// SWAP is a C-type gate mapping Z_a ↔ Z_b and X_a ↔ X_b, so it only
+    /// exchanges rows `qarg1` and `qarg2` of G, F, M and gamma.
+    ///
+    /// Time complexity: O(n)
     pub(crate) fn _left_multiply_swap(&mut self, qarg1: usize, qarg2: usize) {
-        let perm:Vec<usize> = (0..self.n).map(|x| match x {
-            _ if x == qarg1 => qarg2,
-            _ if x == qarg2 => qarg1,
-            _ => x,
-        }).collect();
-        self._permute(&perm);
+        if qarg1 >= self.n || qarg2 >= self.n {
+            panic!("Qubit index out of bounds.");
+        }
+        if qarg1 == qarg2 {
+            return;
+        }
+        for j in 0..self.n {
+            self.mat_g.swap([qarg1, j], [qarg2, j]);
+            self.mat_f.swap([qarg1, j], [qarg2, j]);
+            self.mat_m.swap([qarg1, j], [qarg2, j]);
+        }
+        self.gamma.swap(qarg1, qarg2);
     }
-}
\ No newline at end of file
+}
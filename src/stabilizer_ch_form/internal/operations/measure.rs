@@ -1,10 +1,15 @@
 use crate::StabilizerCHForm;
+use crate::api::operations::Basis;
 
 use crate::stabilizer_ch_form::internal::types::measurement::QubitState;
-use rand::random;
+use rand::Rng;
 
 impl StabilizerCHForm {
     pub(crate) fn _measure(&mut self, qarg: usize) -> bool {
+        self._measure_with_rng(qarg, &mut rand::thread_rng())
+    }
+
+    pub(crate) fn _measure_with_rng<R: Rng + ?Sized>(&mut self, qarg: usize, rng: &mut R) -> bool {
         if qarg >= self.n {
             panic!("Qubit index out of bounds.");
         }
@@ -14,11 +19,26 @@ impl StabilizerCHForm {
             QubitState::Determined(state) => state,
             QubitState::Superposition => {
                 // Randomly collapse the qubit to |0> or |1>
-                let outcome = random::<bool>();
+                let outcome = rng.gen_bool(0.5);
                 self._project(qarg, outcome)
                     .expect("Projection failed during measurement.");
                 outcome
             }
         }
     }
+
+    /// Measures the qubit at index `qarg` in the eigenbasis of `basis`.
+    ///
+    /// Returns `false` for the +1 eigenvalue and `true` for the -1 eigenvalue.
+    pub(crate) fn _measure_in_basis_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        qarg: usize,
+        basis: Basis,
+        rng: &mut R,
+    ) -> bool {
+        self._rotate_basis_to_z(qarg, basis);
+        let outcome = self._measure_with_rng(qarg, rng);
+        self._rotate_basis_from_z(qarg, basis);
+        outcome
+    }
 }
@@ -0,0 +1,22 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::PauliString;
+use rand::Rng;
+
+impl StabilizerCHForm {
+    /// Measures the Pauli observable `pauli` and returns `true` for the -1 eigenvalue.
+    ///
+    /// The observable is rotated onto a single Z, measured, and rotated back.
+    pub(crate) fn _measure_pauli_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        pauli: &PauliString,
+        rng: &mut R,
+    ) -> bool {
+        let Some(pivot) = self._rotate_pauli_to_z(pauli) else {
+            // ±I has a fixed eigenvalue.
+            return pauli.negative;
+        };
+        let outcome = self._measure_with_rng(pivot, rng);
+        self._unrotate_pauli_from_z(pauli);
+        outcome ^ pauli.negative
+    }
+}
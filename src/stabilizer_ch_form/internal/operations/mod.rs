@@ -1,4 +1,7 @@
 pub mod get_qubit_state;
 pub mod inner_product;
 pub mod measure;
+pub mod measure_pauli;
+pub mod probability;
 pub mod project;
+pub mod reset;
@@ -0,0 +1,99 @@
+use crate::StabilizerCHForm;
+use crate::stabilizer_ch_form::internal::types::measurement::QubitState;
+
+impl StabilizerCHForm {
+    /// Returns `Some(k)` if the Z-basis outcomes in `assignment` occur with probability
+    /// 2^(-k), or `None` if they are impossible.
+    ///
+    /// The support of the state is the affine set {G t : t_j = s_j wherever v_j = 0},
+    /// with equal weight on each point. Qubit q is therefore
+    /// x_q = c_q ⊕ Σ_{v_j = 1} G_{q,j} t_j (see `_support_offset`), and `assignment`
+    /// is a linear system in the free bits t_j. It has probability 2^(-rank) if it is
+    /// consistent, where rank is that of the rows of G for the assigned qubits restricted
+    /// to the free columns, and probability 0 otherwise.
+    ///
+    /// Time complexity: O(k n min(k, n)) where k is the length of `assignment`.
+    pub(crate) fn _probability_exponent(&self, assignment: &[(usize, bool)]) -> Option<usize> {
+        if let [(qarg, outcome)] = *assignment {
+            return match self._get_qubit_state(qarg) {
+                QubitState::Determined(value) => (value == outcome).then_some(0),
+                QubitState::Superposition => Some(1),
+            };
+        }
+
+        let free: Vec<usize> = (0..self.n).filter(|&j| self.vec_v[j]).collect();
+        // Reduced rows as (pivot column, row over the free columns, right-hand side).
+        let mut pivots: Vec<(usize, Vec<bool>, bool)> = Vec::new();
+        for &(qarg, outcome) in assignment {
+            let mut row: Vec<bool> = free.iter().map(|&j| self.mat_g[[qarg, j]]).collect();
+            let mut rhs = outcome ^ self._support_offset(qarg);
+            for (col, pivot_row, pivot_rhs) in &pivots {
+                if row[*col] {
+                    row.iter_mut().zip(pivot_row).for_each(|(a, &b)| *a ^= b);
+                    rhs ^= pivot_rhs;
+                }
+            }
+            match row.iter().position(|&b| b) {
+                Some(col) => pivots.push((col, row, rhs)),
+                None if rhs => return None,
+                None => {}
+            }
+        }
+        Some(pivots.len())
+    }
+
+    /// Returns the joint distribution of the Z-basis outcomes of `qubits`, indexed
+    /// little-endian.
+    ///
+    /// With x_q as in `_probability_exponent`, the outcomes form the affine image
+    /// c ⊕ span{g_j : v_j = 1}, where bit k of c and g_j is c_q and G_{q,j} for
+    /// q = `qubits[k]`. The distribution is uniform over the 2^rank points of the image.
+    ///
+    /// Time complexity: O(k n + 2^k) where k is the length of `qubits`.
+    pub(crate) fn _marginal_distribution(&self, qubits: &[usize]) -> Vec<f64> {
+        let mask = |bit: &dyn Fn(usize) -> bool| -> usize {
+            qubits
+                .iter()
+                .enumerate()
+                .filter(|&(_, &q)| bit(q))
+                .map(|(k, _)| 1 << k)
+                .sum()
+        };
+        let offset = mask(&|q| self._support_offset(q));
+
+        // Basis of the span, each element reduced by the earlier ones.
+        let mut basis: Vec<usize> = Vec::new();
+        for j in (0..self.n).filter(|&j| self.vec_v[j]) {
+            let column = mask(&|q| self.mat_g[[q, j]]);
+            let reduced = basis.iter().fold(column, |acc, &b| acc.min(acc ^ b));
+            if reduced != 0 {
+                basis.push(reduced);
+            }
+        }
+
+        let weight = 0.5_f64.powi(basis.len() as i32);
+        let mut distribution = vec![0.0; 1 << qubits.len()];
+        for k in 0..1usize << basis.len() {
+            let index = basis
+                .iter()
+                .enumerate()
+                .filter(|&(bit, _)| (k >> bit) & 1 == 1)
+                .fold(offset, |acc, (_, &b)| acc ^ b);
+            distribution[index] = weight;
+        }
+        distribution
+    }
+
+    /// Returns c_q = Σ_{v_j = 0} G_{q,j} s_j, the value of qubit `qarg` at the point
+    /// of the support where all free bits are 0.
+    fn _support_offset(&self, qarg: usize) -> bool {
+        if qarg >= self.n {
+            panic!("Qubit index out of bounds.");
+        }
+        (0..self.n)
+            .filter(|&j| !self.vec_v[j] && self.vec_s[j] && self.mat_g[[qarg, j]])
+            .count()
+            % 2
+            == 1
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     StabilizerCHForm,
+    api::operations::Basis,
     stabilizer_ch_form::internal::types::{PhaseFactor, measurement::QubitState},
 };
 
@@ -48,4 +49,18 @@ impl StabilizerCHForm {
             }
         }
     }
+
+    /// Projects the qubit at index `qarg` onto the eigenstate of `basis` with
+    /// eigenvalue (-1)^outcome. The state is left unchanged on failure.
+    pub(crate) fn _project_in_basis(
+        &mut self,
+        qarg: usize,
+        basis: Basis,
+        outcome: bool,
+    ) -> Result<(), &'static str> {
+        self._rotate_basis_to_z(qarg, basis);
+        let result = self._project(qarg, outcome);
+        self._rotate_basis_from_z(qarg, basis);
+        result
+    }
 }
@@ -0,0 +1,24 @@
+use crate::StabilizerCHForm;
+use crate::api::operations::Basis;
+use rand::Rng;
+
+impl StabilizerCHForm {
+    /// Resets the qubit at index `qarg` to |0> by measuring it and flipping it on outcome 1.
+    pub(crate) fn _reset_with_rng<R: Rng + ?Sized>(&mut self, qarg: usize, rng: &mut R) {
+        if self._measure_with_rng(qarg, rng) {
+            self._left_multiply_x(qarg);
+        }
+    }
+
+    /// Resets the qubit at index `qarg` to the +1 eigenstate of `basis`.
+    pub(crate) fn _reset_in_basis_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        qarg: usize,
+        basis: Basis,
+        rng: &mut R,
+    ) {
+        self._rotate_basis_to_z(qarg, basis);
+        self._reset_with_rng(qarg, rng);
+        self._rotate_basis_from_z(qarg, basis);
+    }
+}
@@ -1,25 +1,94 @@
 use crate::StabilizerCHForm;
+use crate::api::structure::DiscardError;
+use crate::stabilizer_ch_form::internal::types::measurement::QubitState;
 use ndarray::{Array1, Array2};
+use rand::Rng;
 
 impl StabilizerCHForm {
     /// Discards (traces out) the qubit at index `qarg`.
     ///
-    /// This is an in-place operation that modifies the state.
-    /// NOTE: This function assumes that the qubit `qarg` has already been
-    /// projected onto the |0> state and is disentangled from the rest.
-    pub(crate) fn _discard(&mut self, qarg: usize) -> Result<(), &'static str> {
-        if self.n == 0 {
-            return Err("Cannot discard a qubit from an empty state.");
+    /// This is an in-place operation that modifies the state. The qubit must be
+    /// deterministically |0> or |1> according to `_get_qubit_state`, which implies
+    /// it is disentangled from the rest; |1> is first flipped to |0> with X.
+    pub(crate) fn _discard(&mut self, qarg: usize) -> Result<(), DiscardError> {
+        self._check_discardable(qarg)?;
+        if self.n == 1 {
+            return Err(DiscardError::NoQubitsLeft);
         }
+        self._remove_basis_qubit(qarg);
+        Ok(())
+    }
+
+    /// Discards the qubits `qargs` after checking all of them, so that the state
+    /// is unchanged on error. Qubits are removed from the highest index down, so
+    /// the indices of the ones still to be removed stay valid.
+    pub(crate) fn _discard_many(&mut self, qargs: &[usize]) -> Result<(), DiscardError> {
+        for (i, &qarg) in qargs.iter().enumerate() {
+            self._check_discardable(qarg)?;
+            if qargs[..i].contains(&qarg) {
+                return Err(DiscardError::RepeatedQubit(qarg));
+            }
+        }
+        if !qargs.is_empty() && qargs.len() == self.n {
+            return Err(DiscardError::NoQubitsLeft);
+        }
+
+        let mut sorted = qargs.to_vec();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        for qarg in sorted {
+            self._remove_basis_qubit(qarg);
+        }
+        Ok(())
+    }
+
+    /// Measures the qubit at index `qarg` in the Z basis and discards it.
+    ///
+    /// The measurement leaves the qubit in |0> or |1>, so `_discard` applies.
+    pub(crate) fn _discard_measured_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        qarg: usize,
+        rng: &mut R,
+    ) -> Result<bool, DiscardError> {
+        if qarg >= self.n {
+            return Err(DiscardError::QubitOutOfBounds {
+                qarg,
+                n_qubits: self.n,
+            });
+        }
+        if self.n == 1 {
+            return Err(DiscardError::NoQubitsLeft);
+        }
+        let outcome = self._measure_with_rng(qarg, rng);
+        self._discard(qarg)?;
+        Ok(outcome)
+    }
+
+    /// Checks that `qarg` is in bounds and deterministically |0> or |1>.
+    fn _check_discardable(&self, qarg: usize) -> Result<(), DiscardError> {
         if qarg >= self.n {
-            panic!("Qubit index out of bounds.");
+            return Err(DiscardError::QubitOutOfBounds {
+                qarg,
+                n_qubits: self.n,
+            });
+        }
+        match self._get_qubit_state(qarg) {
+            QubitState::Determined(_) => Ok(()),
+            QubitState::Superposition => Err(DiscardError::NotInBasisState(qarg)),
+        }
+    }
+
+    /// Removes `qarg`, which must be deterministically |0> or |1>.
+    fn _remove_basis_qubit(&mut self, qarg: usize) {
+        if self._get_qubit_state(qarg) == QubitState::Determined(true) {
+            self._left_multiply_x(qarg);
         }
 
         // Ensure s[qarg], v[qarg] are false
         // and also G[qarg, :] and G[:, qarg] are zero except for the diagonal.
         // Also ensure M[qarg, :] and M[:, qarg] are zero.
-        self._set_s_v_to_false(qarg)?;
-        self._transform_g(qarg)?;
+        const PRECONDITION: &str = "A qubit in |0> can be separated from the CH-form.";
+        self._set_s_v_to_false(qarg).expect(PRECONDITION);
+        self._transform_g(qarg).expect(PRECONDITION);
         self._transform_m(qarg);
 
         // Update self with the new (n-1)-qubit state
@@ -31,18 +100,6 @@ impl StabilizerCHForm {
         self.gamma = self._remove_element_from_vector(&self.gamma, qarg);
         self.vec_v = self._remove_element_from_vector(&self.vec_v, qarg);
         self.vec_s = self._remove_element_from_vector(&self.vec_s, qarg);
-
-        Ok(())
-    }
-
-    /// Returns a new StabilizerCHForm with the specified qubit discarded.
-    ///
-    /// NOTE: This function assumes that the qubit `qarg` has already been
-    /// projected onto the |0> state.
-    pub fn discarded(&self, qarg: usize) -> Result<StabilizerCHForm, &'static str> {
-        let mut self_clone = self.clone();
-        self_clone.discard(qarg)?;
-        Ok(self_clone)
     }
 
     // --- Private helper methods ---
@@ -0,0 +1,21 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::PauliString;
+use crate::api::representation::pauli_string::symplectic_rank;
+
+impl StabilizerCHForm {
+    /// Returns the entanglement entropy in bits between `subsystem` and the rest.
+    ///
+    /// For a pure stabilizer state S(A) = rank(S|_A) - |A|, where S|_A are the
+    /// stabilizer generators restricted to the qubits of A and the rank is over GF(2).
+    /// See Fattal et al., arXiv:quant-ph/0406168.
+    ///
+    /// Time complexity: O(n^3)
+    pub(crate) fn _entanglement_entropy(&self, subsystem: &[usize]) -> usize {
+        let restricted: Vec<PauliString> = self
+            ._stabilizers()
+            .iter()
+            .map(|g| PauliString::new(subsystem.iter().map(|&q| g.paulis[q]).collect(), false))
+            .collect();
+        symplectic_rank(&restricted) - subsystem.len()
+    }
+}
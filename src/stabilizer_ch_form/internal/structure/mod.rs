@@ -1,3 +1,5 @@
 pub mod discard;
+pub mod entanglement;
 pub mod kron;
 pub mod permute;
+pub mod stabilizers;
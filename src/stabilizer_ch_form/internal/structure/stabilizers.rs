@@ -0,0 +1,64 @@
+use crate::StabilizerCHForm;
+use crate::api::representation::{Pauli, PauliString};
+use crate::stabilizer_ch_form::internal::types::PhaseFactor;
+
+impl StabilizerCHForm {
+    /// Returns n independent stabilizer generators of the state.
+    ///
+    /// Qubit i of U_H|s> is stabilized by (-1)^{s_i} Z_i if v_i = 0 and by
+    /// (-1)^{s_i} X_i otherwise, so the state is stabilized by the conjugates by U_C.
+    /// With U_C† Z_p U_C = Z(G_p), U_C† X_p U_C = i^{γ_p} X(F_p) Z(M_p) and
+    /// G^{-1} = F^T:
+    ///   U_C Z_i U_C† = Z(F^T e_i),
+    ///   U_C X_i U_C† = c^* X(x) Z(x M F^T) with x = G^T e_i,
+    /// where U_C† X(x) U_C = c X_i Z(x M) collects the phases i^{γ_p} and the
+    /// signs from reordering the factors.
+    ///
+    /// Time complexity: O(n^3)
+    pub(crate) fn _stabilizers(&self) -> Vec<PauliString> {
+        let n = self.n;
+        (0..n)
+            .map(|i| {
+                let (x, z, mut phase) = if self.vec_v[i] {
+                    let x: Vec<bool> = (0..n).map(|p| self.mat_g[[p, i]]).collect();
+                    // Multiply out U_C† X(x) U_C = c X(b) Z(y), with b = e_i.
+                    let mut y = vec![false; n];
+                    let mut c = PhaseFactor::PLUS_ONE;
+                    for p in (0..n).filter(|&p| x[p]) {
+                        // Z(y) X(F_p) = (-1)^{y·F_p} X(F_p) Z(y)
+                        if (0..n).filter(|&j| y[j] && self.mat_f[[p, j]]).count() % 2 == 1 {
+                            c.flip_sign();
+                        }
+                        c *= self.gamma[p];
+                        for (y_j, &m) in y.iter_mut().zip(self.mat_m.row(p)) {
+                            *y_j ^= m;
+                        }
+                    }
+                    let z: Vec<bool> = (0..n)
+                        .map(|p| (0..n).filter(|&j| y[j] && self.mat_f[[p, j]]).count() % 2 == 1)
+                        .collect();
+                    (x, z, c.conjugated())
+                } else {
+                    let z = (0..n).map(|p| self.mat_f[[p, i]]).collect();
+                    (vec![false; n], z, PhaseFactor::PLUS_ONE)
+                };
+                if self.vec_s[i] {
+                    phase.flip_sign();
+                }
+
+                // X Z = -i Y on every qubit where both bits are set.
+                let paulis: Vec<Pauli> = x
+                    .iter()
+                    .zip(&z)
+                    .map(|(&x, &z)| {
+                        if x && z {
+                            phase *= PhaseFactor::MINUS_I;
+                        }
+                        Pauli::from_bits(x, z)
+                    })
+                    .collect();
+                PauliString::new(paulis, phase == PhaseFactor::MINUS_ONE)
+            })
+            .collect()
+    }
+}
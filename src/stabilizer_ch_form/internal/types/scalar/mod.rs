@@ -13,15 +13,23 @@ pub enum Scalar {
 
 impl Scalar {
     /// Converts the scalar to its `Complex64` representation.
-    pub fn to_complex(&self) -> Complex64 {
+    pub fn to_complex(self) -> Complex64 {
         match self {
             Scalar::Zero => Complex64::new(0.0, 0.0),
             Scalar::NonZero { phase, r } => {
-                let norm = 2.0_f64.powf(-(*r as f64) / 2.0);
+                let norm = 2.0_f64.powf(-(r as f64) / 2.0);
                 phase.to_complex() * norm
             }
         }
     }
+
+    /// Returns `(k, r)` for the value e^{ikπ/4} 2^(-r/2), or `None` for zero.
+    pub(crate) fn to_parts(self) -> Option<(u8, usize)> {
+        match self {
+            Scalar::Zero => None,
+            Scalar::NonZero { phase, r } => Some((phase.to_int(), r)),
+        }
+    }
 }
 
 impl Mul for Scalar {
@@ -1 +1,2 @@
+pub mod pauli_basis_change;
 pub mod resolve_superposition;
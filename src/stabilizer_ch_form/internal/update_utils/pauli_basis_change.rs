@@ -0,0 +1,75 @@
+use crate::StabilizerCHForm;
+use crate::api::operations::Basis;
+use crate::api::representation::{Pauli, PauliString};
+
+impl StabilizerCHForm {
+    /// Applies a single-qubit Clifford `V` that maps the eigenbasis of `basis` on qubit
+    /// `qarg` to the Z basis, i.e. `V P V† = Z`. The +1 eigenstate is mapped to |0>.
+    ///
+    /// X is rotated with H and Y with H Sdg.
+    pub(crate) fn _rotate_basis_to_z(&mut self, qarg: usize, basis: Basis) {
+        match basis {
+            Basis::X => self._left_multiply_h(qarg),
+            Basis::Y => {
+                self._left_multiply_sdg(qarg);
+                self._left_multiply_h(qarg);
+            }
+            Basis::Z => {}
+        }
+    }
+
+    /// Undoes `_rotate_basis_to_z` for the same `qarg` and `basis`.
+    pub(crate) fn _rotate_basis_from_z(&mut self, qarg: usize, basis: Basis) {
+        match basis {
+            Basis::X => self._left_multiply_h(qarg),
+            Basis::Y => {
+                self._left_multiply_h(qarg);
+                self._left_multiply_s(qarg);
+            }
+            Basis::Z => {}
+        }
+    }
+
+    /// Applies a Clifford `V` such that `V P V† = Z_pivot`, up to the sign of `P`,
+    /// and returns `pivot`. Returns `None` if `P` is proportional to the identity.
+    ///
+    /// Each factor is rotated to Z with `_rotate_basis_to_z`, and the resulting
+    /// Z-product is collected onto the first qubit of the support with CNOTs.
+    pub(crate) fn _rotate_pauli_to_z(&mut self, pauli: &PauliString) -> Option<usize> {
+        if pauli.n_qubits() != self.n {
+            panic!("Pauli string length must be equal to the number of qubits.");
+        }
+        let support = pauli.support();
+        let (&pivot, rest) = support.split_first()?;
+        for &q in &support {
+            self._rotate_basis_to_z(q, pauli_basis(pauli.paulis[q]));
+        }
+        for &q in rest {
+            self._left_multiply_cx(q, pivot);
+        }
+        Some(pivot)
+    }
+
+    /// Undoes `_rotate_pauli_to_z` for the same `pauli`.
+    pub(crate) fn _unrotate_pauli_from_z(&mut self, pauli: &PauliString) {
+        let support = pauli.support();
+        let Some((&pivot, rest)) = support.split_first() else {
+            return;
+        };
+        for &q in rest.iter().rev() {
+            self._left_multiply_cx(q, pivot);
+        }
+        for &q in &support {
+            self._rotate_basis_from_z(q, pauli_basis(pauli.paulis[q]));
+        }
+    }
+}
+
+fn pauli_basis(pauli: Pauli) -> Basis {
+    match pauli {
+        Pauli::X => Basis::X,
+        Pauli::Y => Basis::Y,
+        Pauli::Z => Basis::Z,
+        Pauli::I => unreachable!("Identity factors are not part of the support."),
+    }
+}
@@ -1,9 +1,9 @@
 use ndarray::{Array1, Array2};
 use num_complex::Complex64;
+use rand::Rng;
 
 mod internal;
 use crate::api::representation::{CliffordCircuit, CliffordGate};
-use crate::api::gates::*;
 use internal::types::phase_factor::PhaseFactor;
 
 #[derive(Debug, Clone)]
@@ -26,7 +26,7 @@ impl StabilizerCHForm {
         }
 
         Self {
-            n: n,
+            n,
             // Initialize G, F as identity matrices, M as zero matrix
             mat_g: Array2::from_shape_fn((n, n), |(i, j)| i == j),
             mat_f: Array2::from_shape_fn((n, n), |(i, j)| i == j),
@@ -65,26 +65,51 @@ impl StabilizerCHForm {
         let mut ch_form = StabilizerCHForm::new(circuit.n_qubits);
 
         for gate in &circuit.gates {
-            match gate {
-                CliffordGate::H(q) => ch_form._left_multiply_h(*q),
-                CliffordGate::S(q) => ch_form._left_multiply_s(*q),
-                CliffordGate::Sdg(q) => ch_form._left_multiply_sdg(*q),
-                CliffordGate::X(q) => ch_form._left_multiply_x(*q),
-                CliffordGate::Y(q) => ch_form._left_multiply_y(*q),
-                CliffordGate::Z(q) => ch_form._left_multiply_z(*q),
-                CliffordGate::SqrtX(q) => ch_form._left_multiply_sqrt_x(*q),
-                CliffordGate::SqrtXdg(q) => ch_form._left_multiply_sqrt_xdg(*q),
-                CliffordGate::CX(control, target) => {
-                    ch_form._left_multiply_cx(*control, *target)
-                }
-                CliffordGate::CZ(control, target) => {
-                    ch_form._left_multiply_cz(*control, *target)
-                }
-                CliffordGate::Swap(q1, q2) => {
-                    ch_form._left_multiply_swap(*q1, *q2)
-                }
-            }
+            ch_form._apply_gate(gate);
         }
         Ok(ch_form)
     }
+
+    /// Returns a uniformly random n-qubit stabilizer state.
+    ///
+    /// The state is prepared by applying `CliffordCircuit::random` to |0...0>,
+    /// so it is reproducible for a seeded `rng`.
+    ///
+    /// Time complexity: O(n^4)
+    pub fn random<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        if n == 0 {
+            panic!("Number of qubits must be greater than zero.");
+        }
+        let mut ch_form = StabilizerCHForm::new(n);
+        for gate in &CliffordCircuit::random(n, rng).gates {
+            ch_form._apply_gate(gate);
+        }
+        ch_form
+    }
+
+    /// Left-multiplies the state by the unitary of `gate`.
+    pub(crate) fn _apply_gate(&mut self, gate: &CliffordGate) {
+        match gate {
+            CliffordGate::H(q) => self._left_multiply_h(*q),
+            CliffordGate::S(q) => self._left_multiply_s(*q),
+            CliffordGate::Sdg(q) => self._left_multiply_sdg(*q),
+            CliffordGate::X(q) => self._left_multiply_x(*q),
+            CliffordGate::Y(q) => self._left_multiply_y(*q),
+            CliffordGate::Z(q) => self._left_multiply_z(*q),
+            CliffordGate::SqrtX(q) => self._left_multiply_sqrt_x(*q),
+            CliffordGate::SqrtXdg(q) => self._left_multiply_sqrt_xdg(*q),
+            CliffordGate::SqrtY(q) => self._left_multiply_sqrt_y(*q),
+            CliffordGate::SqrtYdg(q) => self._left_multiply_sqrt_ydg(*q),
+            CliffordGate::HXY(q) => self._left_multiply_h_xy(*q),
+            CliffordGate::HYZ(q) => self._left_multiply_h_yz(*q),
+            CliffordGate::CX(control, target) => self._left_multiply_cx(*control, *target),
+            CliffordGate::CY(control, target) => self._left_multiply_cy(*control, *target),
+            CliffordGate::CZ(control, target) => self._left_multiply_cz(*control, *target),
+            CliffordGate::Swap(q1, q2) => self._left_multiply_swap(*q1, *q2),
+            CliffordGate::ISwap(q1, q2) => self._left_multiply_iswap(*q1, *q2),
+            CliffordGate::ISwapdg(q1, q2) => self._left_multiply_iswapdg(*q1, *q2),
+            CliffordGate::ECR(q1, q2) => self._left_multiply_ecr(*q1, *q2),
+            CliffordGate::DCX(q1, q2) => self._left_multiply_dcx(*q1, *q2),
+        }
+    }
 }
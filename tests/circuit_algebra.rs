@@ -0,0 +1,78 @@
+mod common;
+use common::{assert_eq_complex_array1, random_circuit_all_gates};
+use ndarray::Array1;
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn zero_state(n_qubits: usize) -> Array1<Complex64> {
+    let mut statevector = Array1::zeros(1 << n_qubits);
+    statevector[0] = Complex64::new(1.0, 0.0);
+    statevector
+}
+
+#[test]
+fn test_inverse_undoes_circuit_exactly() {
+    for seed in 0..10 {
+        let circuit = random_circuit_all_gates(3, 60, seed);
+        let identity = &circuit + &circuit.inverse();
+        assert_eq!(identity.gates.len(), 120);
+        let state = StabilizerCHForm::try_from(&identity).unwrap();
+        assert_eq_complex_array1(&state.to_statevector(), &zero_state(3));
+    }
+}
+
+#[test]
+fn test_compose_tensor_and_repeat() {
+    let a = random_circuit_all_gates(2, 20, 1);
+    let b = random_circuit_all_gates(3, 20, 2);
+
+    let product = StabilizerCHForm::try_from(&a.tensor(&b).unwrap()).unwrap();
+    let expected = StabilizerCHForm::try_from(&a)
+        .unwrap()
+        .kron(&StabilizerCHForm::try_from(&b).unwrap());
+    assert_eq_complex_array1(&product.to_statevector(), &expected.to_statevector());
+
+    // Running `a` on qubits 4 and 1 of a 5-qubit register.
+    let mut register = CliffordCircuit::new(5);
+    register.apply_x(0);
+    let composed = register.compose_on(&a, &[4, 1]).unwrap();
+    assert_eq!(composed.gates[0], CliffordGate::X(0));
+    let mapped = a.remap(&[4, 1], 5).unwrap();
+    assert_eq!(&composed.gates[1..], &mapped.gates[..]);
+    assert_eq!(a.gates[0].map_qubits(|q| [4, 1][q]), composed.gates[1]);
+
+    let mut s = CliffordCircuit::new(1);
+    s.apply_s(0);
+    assert_eq!((&s * 3).gates.len(), 3);
+    assert_eq!(
+        (s.clone() * 3).inverse().gates,
+        vec![CliffordGate::Sdg(0); 3]
+    );
+    let state = StabilizerCHForm::try_from(&(s.clone() + s.repeat(3))).unwrap();
+    assert_eq_complex_array1(&state.to_statevector(), &zero_state(1));
+}
+
+#[test]
+fn test_validation() {
+    let mut invalid = CliffordCircuit::new(2);
+    invalid.apply_cx(0, 2);
+    assert!(invalid.validate().is_err());
+    let mut repeated = CliffordCircuit::new(2);
+    repeated.apply_cz(1, 1);
+    assert!(repeated.validate().is_err());
+
+    let valid = random_circuit_all_gates(2, 10, 0);
+    assert!(valid.compose(&invalid).is_err());
+    assert!(valid.compose(&CliffordCircuit::new(3)).is_err());
+    assert!(valid.tensor(&invalid).is_err());
+    assert!(valid.remap(&[0], 2).is_err());
+    assert!(valid.remap(&[0, 0], 2).is_err());
+    assert!(valid.remap(&[0, 2], 2).is_err());
+    assert!(valid.compose_on(&valid, &[1, 0]).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_add_panics_on_size_mismatch() {
+    let _ = CliffordCircuit::new(2) + CliffordCircuit::new(3);
+}
@@ -0,0 +1,80 @@
+mod common;
+use common::random_circuit_all_gates;
+use stabilizer_ch_form_rust::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+fn example() -> CliffordCircuit {
+    CliffordCircuit::builder(5)
+        .h(0)
+        .x(3)
+        .cx(0, 1)
+        .cz(1, 2)
+        .s(0)
+        .h(2)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_depth_and_counts() {
+    let circuit = example();
+    assert_eq!(circuit.depth(), 4);
+    assert_eq!(circuit.two_qubit_depth(), 2);
+    assert_eq!(circuit.two_qubit_gate_count(), 2);
+    assert_eq!(
+        circuit.gate_counts(),
+        BTreeMap::from([("cx", 1), ("cz", 1), ("h", 2), ("s", 1), ("x", 1)])
+    );
+    assert_eq!(
+        circuit.interaction_graph(),
+        BTreeMap::from([((0, 1), 1), ((1, 2), 1)])
+    );
+    assert_eq!(circuit.used_qubits(), BTreeSet::from([0, 1, 2, 3]));
+    assert_eq!(CliffordCircuit::new(3).depth(), 0);
+}
+
+#[test]
+fn test_asap_and_alap_layers() {
+    use CliffordGate::*;
+    let circuit = example();
+    assert_eq!(
+        circuit.layers_asap(),
+        vec![
+            vec![H(0), X(3)],
+            vec![CX(0, 1)],
+            vec![CZ(1, 2), S(0)],
+            vec![H(2)],
+        ]
+    );
+    assert_eq!(
+        circuit.layers_alap(),
+        vec![
+            vec![H(0)],
+            vec![CX(0, 1)],
+            vec![CZ(1, 2)],
+            vec![X(3), S(0), H(2)],
+        ]
+    );
+}
+
+#[test]
+fn test_layers_are_valid_schedules() {
+    for seed in 0..5 {
+        let circuit = random_circuit_all_gates(5, 80, seed);
+        for layers in [circuit.layers_asap(), circuit.layers_alap()] {
+            assert_eq!(layers.len(), circuit.depth());
+            let mut flattened = CliffordCircuit::new(5);
+            for layer in &layers {
+                let qubits: Vec<usize> = layer.iter().flat_map(|g| g.qubits()).collect();
+                let distinct: BTreeSet<usize> = qubits.iter().copied().collect();
+                assert_eq!(
+                    qubits.len(),
+                    distinct.len(),
+                    "Overlapping gates in a moment"
+                );
+                flattened.add_multiple_gates(layer.clone());
+            }
+            assert!(flattened.equivalent(&circuit, false).unwrap());
+        }
+    }
+}
@@ -0,0 +1,107 @@
+mod common;
+use common::assert_eq_complex_array1;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_builder_covers_every_gate() {
+    let circuit = CliffordCircuit::builder(2)
+        .h(0)
+        .x(0)
+        .y(0)
+        .z(0)
+        .s(0)
+        .sdg(0)
+        .sqrt_x(0)
+        .sqrt_xdg(0)
+        .sqrt_y(0)
+        .sqrt_ydg(0)
+        .h_xy(0)
+        .h_yz(0)
+        .cx(0, 1)
+        .cy(0, 1)
+        .cz(0, 1)
+        .swap(0, 1)
+        .iswap(0, 1)
+        .iswapdg(0, 1)
+        .ecr(0, 1)
+        .dcx(0, 1)
+        .build()
+        .unwrap();
+
+    let mut expected = CliffordCircuit::new(2);
+    expected.apply_h(0);
+    expected.apply_x(0);
+    expected.apply_y(0);
+    expected.apply_z(0);
+    expected.apply_s(0);
+    expected.apply_sdg(0);
+    expected.apply_sqrt_x(0);
+    expected.apply_sqrt_xdg(0);
+    expected.apply_sqrt_y(0);
+    expected.apply_sqrt_ydg(0);
+    expected.apply_h_xy(0);
+    expected.apply_h_yz(0);
+    expected.apply_cx(0, 1);
+    expected.apply_cy(0, 1);
+    expected.apply_cz(0, 1);
+    expected.apply_swap(0, 1);
+    expected.apply_iswap(0, 1);
+    expected.apply_iswapdg(0, 1);
+    expected.apply_ecr(0, 1);
+    expected.apply_dcx(0, 1);
+    assert_eq!(circuit.gates, expected.gates);
+}
+
+#[test]
+fn test_range_helpers() {
+    let ghz = CliffordCircuit::builder(4)
+        .h(0)
+        .cx_ladder(0..4)
+        .build()
+        .unwrap();
+    let statevector = StabilizerCHForm::try_from(&ghz).unwrap().to_statevector();
+    let r = num_complex::Complex64::new(1.0 / 2f64.sqrt(), 0.0);
+    let mut expected = ndarray::Array1::zeros(16);
+    expected[0] = r;
+    expected[15] = r;
+    assert_eq_complex_array1(&statevector, &expected);
+
+    let circuit = CliffordCircuit::builder(3)
+        .h_all()
+        .layer([0, 2], CliffordGate::S)
+        .layer(0..2, |q| CliffordGate::CZ(q, q + 1))
+        .circuit(&CliffordCircuit::builder(3).x(1).build().unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(
+        circuit.gates,
+        vec![
+            CliffordGate::H(0),
+            CliffordGate::H(1),
+            CliffordGate::H(2),
+            CliffordGate::S(0),
+            CliffordGate::S(2),
+            CliffordGate::CZ(0, 1),
+            CliffordGate::CZ(1, 2),
+            CliffordGate::X(1),
+        ]
+    );
+}
+
+#[test]
+fn test_builder_validation() {
+    let error = CliffordCircuit::builder(2).h(0).cx(0, 2).h(1).build();
+    assert!(error.unwrap_err().contains("out of bounds"));
+    assert!(CliffordCircuit::builder(2).cz(1, 1).build().is_err());
+    assert!(
+        CliffordCircuit::builder(2)
+            .circuit(&CliffordCircuit::new(3))
+            .build()
+            .is_err()
+    );
+
+    let mut circuit = CliffordCircuit::new(2);
+    assert!(circuit.try_add_gate(CliffordGate::Swap(0, 1)).is_ok());
+    assert!(circuit.try_add_gate(CliffordGate::SqrtX(2)).is_err());
+    assert_eq!(circuit.gates.len(), 1);
+}
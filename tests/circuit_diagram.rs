@@ -0,0 +1,70 @@
+use stabilizer_ch_form_rust::prelude::*;
+
+fn example() -> CliffordCircuit {
+    CliffordCircuit::builder(4)
+        .h(0)
+        .cx(0, 2)
+        .s(1)
+        .cz(3, 1)
+        .swap(0, 1)
+        .sdg(2)
+        .ecr(2, 3)
+        .dcx(3, 0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_display() {
+    let expected = [
+        "q0: ──H──●──────×──────────DCX₁──",
+        "         │      │            │",
+        "q1: ──S──┼──●───×────────────┼───",
+        "         │  │                │",
+        "q2: ─────⊕──┼──S†───ECR₀─────┼───",
+        "            │         │      │",
+        "q3: ────────●───────ECR₁───DCX₀──",
+    ]
+    .join("\n");
+    assert_eq!(example().to_string(), expected);
+    assert_eq!(CliffordCircuit::new(2).to_string(), "q0: ─\n\nq1: ─");
+    assert_eq!(CliffordCircuit::new(0).to_string(), "");
+}
+
+#[test]
+fn test_fold() {
+    let circuit = example();
+    let folded = format!("{:30}", circuit);
+    assert_eq!(folded, circuit.draw(Some(30)));
+    assert!(folded.lines().all(|line| line.chars().count() <= 30));
+    assert_eq!(folded.lines().filter(|l| l.starts_with("q0: ")).count(), 2);
+    assert!(folded.contains("ECR₀──»\n"));
+    assert!(folded.contains("q3: «─DCX₀──"));
+
+    // A column wider than the fold width still gets drawn.
+    let narrow = circuit.draw(Some(1));
+    assert_eq!(narrow.lines().filter(|l| l.starts_with("q0: ")).count(), 6);
+    assert_eq!(circuit.draw(Some(1000)), circuit.to_string());
+}
+
+#[test]
+fn test_all_gates_have_labels() {
+    let circuit = CliffordCircuit::builder(2)
+        .sqrt_x(0)
+        .sqrt_xdg(0)
+        .sqrt_y(0)
+        .sqrt_ydg(0)
+        .h_xy(0)
+        .h_yz(0)
+        .cy(0, 1)
+        .iswap(0, 1)
+        .iswapdg(1, 0)
+        .build()
+        .unwrap();
+    let diagram = circuit.to_string();
+    for label in [
+        "√X", "√X†", "√Y", "√Y†", "Hxy", "Hyz", "●", "iSwap", "iSwap†",
+    ] {
+        assert!(diagram.contains(label), "{} missing in\n{}", label, diagram);
+    }
+}
@@ -0,0 +1,93 @@
+mod common;
+use common::{random_circuit, random_circuit_all_gates};
+use ndarray::{Array1, array};
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_tableau_layout() {
+    let mut circuit = CliffordCircuit::new(1);
+    circuit.apply_h(0);
+    let h = CliffordTableau::from_circuit(&circuit).unwrap();
+    assert_eq!(h.symplectic(), &array![[false, true], [true, false]]);
+
+    // S maps X to Y and Y to -X.
+    circuit.apply_s(0);
+    let hs = CliffordTableau::from_circuit(&circuit).unwrap();
+    assert_eq!(hs.destabilizer(0).to_string(), "+Z");
+    assert_eq!(hs.stabilizer(0).to_string(), "+Y");
+    circuit.apply_s(0);
+    let hz = CliffordTableau::from_circuit(&circuit).unwrap();
+    assert_eq!(hz.stabilizer(0).to_string(), "-X");
+
+    let mut circuit = CliffordCircuit::new(2);
+    circuit.apply_cx(0, 1);
+    let cx = CliffordTableau::from_circuit(&circuit).unwrap();
+    assert_eq!(cx.destabilizer(0).to_string(), "+XX");
+    assert_eq!(cx.stabilizer(0).to_string(), "+ZI");
+    assert_eq!(cx.destabilizer(1).to_string(), "+IX");
+    assert_eq!(cx.stabilizer(1).to_string(), "+ZZ");
+
+    let identity = CliffordTableau::identity(2);
+    assert_eq!(
+        CliffordTableau::new(identity.symplectic().clone(), identity.phases().clone()),
+        Ok(identity)
+    );
+    assert!(
+        CliffordTableau::new(
+            ndarray::Array2::from_elem((4, 4), true),
+            Array1::from_elem(4, false)
+        )
+        .is_err()
+    );
+    assert!(
+        CliffordTableau::new(
+            ndarray::Array2::from_elem((4, 4), false),
+            Array1::from_elem(3, false)
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_synthesis_round_trip() {
+    for seed in 0..20 {
+        let circuit = random_circuit_all_gates(4, 60, seed);
+        let tableau = CliffordTableau::from_circuit(&circuit).unwrap();
+        let synthesized = tableau.to_circuit();
+        assert_eq!(
+            CliffordTableau::from_circuit(&synthesized).unwrap(),
+            tableau
+        );
+
+        let expected = StabilizerCHForm::try_from(&circuit).unwrap();
+        let mut actual = StabilizerCHForm::new(4);
+        actual.apply_clifford(&tableau, &[0, 1, 2, 3]).unwrap();
+        assert!((expected.inner_product(&actual).norm() - 1.0).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn test_apply_clifford_on_subset() {
+    let qargs = [3, 0, 4];
+    for seed in 0..10 {
+        let base = random_circuit(5, 40, Some(seed));
+        let operation = random_circuit_all_gates(3, 30, seed + 100);
+        let tableau = CliffordTableau::from_circuit(&operation).unwrap();
+
+        let mut actual = StabilizerCHForm::try_from(&base).unwrap();
+        actual.apply_clifford(&tableau, &qargs).unwrap();
+
+        let mut circuit = base.clone();
+        for gate in &operation.gates {
+            circuit.add_gate(gate.map_qubits(|q| qargs[q]));
+        }
+        let expected = StabilizerCHForm::try_from(&circuit).unwrap();
+        assert!((expected.inner_product(&actual).norm() - 1.0).abs() < 1e-8);
+    }
+
+    let tableau = CliffordTableau::identity(2);
+    let mut state = StabilizerCHForm::new(3);
+    assert!(state.apply_clifford(&tableau, &[0]).is_err());
+    assert!(state.apply_clifford(&tableau, &[1, 1]).is_err());
+    assert!(state.apply_clifford(&tableau, &[0, 3]).is_err());
+}
@@ -121,8 +121,8 @@ pub fn random_circuit(n_qubits: usize, gate_count: usize, seed: Option<u64>) ->
                     q2 = rng.gen_range(0..n_qubits);
                 }
                 let gate = match gate_type {
-                    4 => CliffordGate::CX(q1, q2),
-                    5 => CliffordGate::CZ(q1, q2),
+                    6 => CliffordGate::CX(q1, q2),
+                    7 => CliffordGate::CZ(q1, q2),
                     _ => unreachable!(),
                 };
                 circuit.add_gate(gate);
@@ -132,3 +132,37 @@ pub fn random_circuit(n_qubits: usize, gate_count: usize, seed: Option<u64>) ->
     }
     circuit
 }
+
+/// Returns a random circuit drawing from every supported gate.
+#[allow(dead_code)]
+pub fn random_circuit_all_gates(n_qubits: usize, gate_count: usize, seed: u64) -> CliffordCircuit {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut circuit = CliffordCircuit::new(n_qubits);
+    for _ in 0..gate_count {
+        let a = rng.gen_range(0..n_qubits);
+        let b = (a + rng.gen_range(1..n_qubits)) % n_qubits;
+        circuit.add_gate(match rng.gen_range(0..20) {
+            0 => CliffordGate::H(a),
+            1 => CliffordGate::X(a),
+            2 => CliffordGate::Y(a),
+            3 => CliffordGate::Z(a),
+            4 => CliffordGate::S(a),
+            5 => CliffordGate::Sdg(a),
+            6 => CliffordGate::SqrtX(a),
+            7 => CliffordGate::SqrtXdg(a),
+            8 => CliffordGate::SqrtY(a),
+            9 => CliffordGate::SqrtYdg(a),
+            10 => CliffordGate::HXY(a),
+            11 => CliffordGate::HYZ(a),
+            12 => CliffordGate::CX(a, b),
+            13 => CliffordGate::CY(a, b),
+            14 => CliffordGate::CZ(a, b),
+            15 => CliffordGate::Swap(a, b),
+            16 => CliffordGate::ISwap(a, b),
+            17 => CliffordGate::ISwapdg(a, b),
+            18 => CliffordGate::ECR(a, b),
+            _ => CliffordGate::DCX(a, b),
+        });
+    }
+    circuit
+}
@@ -0,0 +1,102 @@
+mod common;
+use common::{assert_eq_complex, random_circuit_all_gates};
+use ndarray::Array2;
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn assert_eq_matrix(a: &Array2<Complex64>, b: &Array2<Complex64>) {
+    assert_eq!(a.dim(), b.dim());
+    for (x, y) in a.iter().zip(b) {
+        assert_eq_complex(*x, *y);
+    }
+}
+
+/// Computes the reduced density matrix from the dense statevector.
+fn reduced_from_statevector(state: &StabilizerCHForm, keep: &[usize]) -> Array2<Complex64> {
+    let n = state.n_qubits();
+    let psi = state.to_statevector();
+    let traced: Vec<usize> = (0..n).filter(|q| !keep.contains(q)).collect();
+    let spread = |bits: usize, qubits: &[usize]| -> usize {
+        qubits
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| (bits >> i) & 1 == 1)
+            .map(|(_, &q)| 1 << q)
+            .sum()
+    };
+    let dim = 1 << keep.len();
+    Array2::from_shape_fn((dim, dim), |(a, a2)| {
+        (0..1 << traced.len())
+            .map(|b| {
+                let rest = spread(b, &traced);
+                psi[spread(a, keep) | rest] * psi[spread(a2, keep) | rest].conj()
+            })
+            .sum()
+    })
+}
+
+#[test]
+fn test_density_matrix_of_pure_state() {
+    for seed in 0..10 {
+        let state = StabilizerCHForm::try_from(&random_circuit_all_gates(3, 50, seed)).unwrap();
+        let psi = state.to_statevector();
+        let expected = Array2::from_shape_fn((8, 8), |(i, j)| psi[i] * psi[j].conj());
+        assert_eq_matrix(&state.to_density_matrix().unwrap(), &expected);
+
+        let mixed = state.to_mixed_state();
+        assert!(mixed.is_pure());
+        assert_eq_matrix(&mixed.to_density_matrix().unwrap(), &expected);
+    }
+    assert!(StabilizerCHForm::new(11).to_density_matrix().is_err());
+}
+
+#[test]
+fn test_reduced_density_matrix() {
+    let keeps: [&[usize]; 5] = [&[0], &[3, 1], &[2, 0, 3], &[0, 1, 2, 3], &[]];
+    for seed in 0..10 {
+        let state = StabilizerCHForm::try_from(&random_circuit_all_gates(4, 60, seed)).unwrap();
+        for &keep in &keeps {
+            let reduced = state.reduced_density_matrix(keep).unwrap();
+            assert_eq!(reduced.n_qubits(), keep.len());
+            assert_eq_matrix(
+                &reduced.to_density_matrix().unwrap(),
+                &reduced_from_statevector(&state, keep),
+            );
+        }
+    }
+
+    let mut bell = StabilizerCHForm::new(3);
+    bell.apply_h(0);
+    bell.apply_cx(0, 1);
+    bell.apply_x(2);
+    assert_eq!(
+        bell.reduced_density_matrix(&[0]).unwrap(),
+        MixedStabilizerState::maximally_mixed(1)
+    );
+    let last = bell.reduced_density_matrix(&[2]).unwrap();
+    assert!(last.is_pure());
+    assert_eq!(last.generators()[0].to_string(), "-Z");
+    assert!(bell.reduced_density_matrix(&[0, 0]).is_err());
+    assert!(bell.reduced_density_matrix(&[3]).is_err());
+}
+
+#[test]
+fn test_mixed_state_validation() {
+    let p = |s: &str| s.parse::<PauliString>().unwrap();
+    assert!(MixedStabilizerState::new(2, vec![p("XX"), p("ZZ")]).is_ok());
+    assert!(MixedStabilizerState::new(2, vec![p("XI"), p("ZI")]).is_err());
+    assert!(MixedStabilizerState::new(2, vec![p("XX"), p("-XX")]).is_err());
+    assert!(MixedStabilizerState::new(2, vec![p("XXX")]).is_err());
+
+    assert_eq!(p("XX").product(&p("ZZ")).unwrap(), p("-YY"));
+    assert_eq!(p("XY").product(&p("YX")).unwrap(), p("+ZZ"));
+    assert!(p("XI").product(&p("ZI")).is_err());
+
+    let rho = MixedStabilizerState::new(2, vec![p("-ZI")])
+        .unwrap()
+        .to_density_matrix()
+        .unwrap();
+    // (I - Z_0)/4 projects qubit 0 onto |1>.
+    let diagonal: Vec<f64> = rho.diag().iter().map(|z| z.re).collect();
+    assert_eq!(diagonal, vec![0.0, 0.5, 0.0, 0.5]);
+}
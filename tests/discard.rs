@@ -0,0 +1,95 @@
+mod common;
+use common::{assert_eq_complex_array1, random_circuit_all_gates};
+use stabilizer_ch_form_rust::prelude::*;
+
+/// Returns `state` with basis-state ancillas inserted, so that qubits
+/// (0, 1, 2, 3, 4) are (s0, a0, s1, s2, a1).
+fn with_ancillas(state: &StabilizerCHForm, a0: bool, a1: bool) -> StabilizerCHForm {
+    let mut ancillas = StabilizerCHForm::new(2);
+    if a0 {
+        ancillas.apply_x(0);
+    }
+    if a1 {
+        ancillas.apply_x(1);
+    }
+    // Hide the ancillas' basis state behind some gates that leave it invariant.
+    ancillas.apply_cz(0, 1);
+    ancillas.apply_s(1);
+    state.kron(&ancillas).permuted(&[0, 3, 1, 2, 4])
+}
+
+#[test]
+fn test_discard_basis_qubits() {
+    for seed in 0..20 {
+        let state = StabilizerCHForm::try_from(&random_circuit_all_gates(3, 60, seed)).unwrap();
+        let expected = state.to_statevector();
+        let (a0, a1) = (seed % 2 == 1, seed % 4 >= 2);
+
+        let mut one_by_one = with_ancillas(&state, a0, a1);
+        one_by_one.discard(4).unwrap();
+        one_by_one.discard(1).unwrap();
+        assert_eq!(one_by_one.n_qubits(), 3);
+        let overlap = state.inner_product(&one_by_one);
+        assert!((overlap.norm() - 1.0).abs() < 1e-8);
+
+        let mut batch = with_ancillas(&state, a0, a1);
+        batch.discard_many(&[1, 4]).unwrap();
+        assert_eq!(batch.n_qubits(), 3);
+        assert_eq_complex_array1(&batch.to_statevector(), &one_by_one.to_statevector());
+        let overlap = expected
+            .iter()
+            .zip(&batch.to_statevector())
+            .map(|(a, b)| a.conj() * b)
+            .sum::<num_complex::Complex64>();
+        assert!((overlap.norm() - 1.0).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn test_discard_errors_leave_state_unchanged() {
+    let mut state = StabilizerCHForm::new(3);
+    state.apply_h(0);
+    state.apply_cx(0, 1);
+    state.apply_x(2);
+    let before = state.to_statevector();
+
+    assert_eq!(state.discard(0), Err(DiscardError::NotInBasisState(0)));
+    assert_eq!(state.discard(1), Err(DiscardError::NotInBasisState(1)));
+    assert_eq!(
+        state.discard(3),
+        Err(DiscardError::QubitOutOfBounds {
+            qarg: 3,
+            n_qubits: 3
+        })
+    );
+    assert_eq!(
+        state.discard_many(&[2, 2]),
+        Err(DiscardError::RepeatedQubit(2))
+    );
+    assert_eq!(
+        state.discard_many(&[2, 0]),
+        Err(DiscardError::NotInBasisState(0))
+    );
+    assert_eq!(state.n_qubits(), 3);
+    assert_eq_complex_array1(&state.to_statevector(), &before);
+
+    state.discard_many(&[]).unwrap();
+    assert!(state.discarded(2).is_ok());
+    state.discard(2).unwrap();
+    assert_eq!(state.n_qubits(), 2);
+
+    let mut single = StabilizerCHForm::new(1);
+    assert_eq!(single.discard(0), Err(DiscardError::NoQubitsLeft));
+    assert_eq!(single.discard_many(&[0]), Err(DiscardError::NoQubitsLeft));
+    assert_eq!(single.discard_measured(0), Err(DiscardError::NoQubitsLeft));
+}
+
+#[test]
+fn test_discard_error_messages() {
+    assert_eq!(
+        DiscardError::NotInBasisState(1).to_string(),
+        "Qubit 1 is not deterministically |0> or |1>; measure or reset it first."
+    );
+    let error: Box<dyn std::error::Error> = Box::new(DiscardError::NoQubitsLeft);
+    assert_eq!(error.to_string(), "Cannot discard every qubit of a state.");
+}
@@ -0,0 +1,114 @@
+mod common;
+use common::random_circuit_all_gates;
+use ndarray::Array1;
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn state(circuit: CliffordCircuit) -> StabilizerCHForm {
+    StabilizerCHForm::try_from(&circuit).unwrap()
+}
+
+/// Applies `pauli` to a little-endian statevector.
+fn apply_pauli(pauli: &PauliString, psi: &Array1<Complex64>) -> Array1<Complex64> {
+    let mut out = Array1::from_elem(psi.len(), Complex64::new(0.0, 0.0));
+    for (i, &amp) in psi.iter().enumerate() {
+        let mut coeff = Complex64::new(if pauli.negative { -1.0 } else { 1.0 }, 0.0);
+        let mut j = i;
+        for (q, &p) in pauli.paulis.iter().enumerate() {
+            let sign = if (i >> q) & 1 == 1 { -1.0 } else { 1.0 };
+            match p {
+                Pauli::I => {}
+                Pauli::X => j ^= 1 << q,
+                Pauli::Y => {
+                    j ^= 1 << q;
+                    coeff *= Complex64::new(0.0, sign);
+                }
+                Pauli::Z => coeff *= sign,
+            }
+        }
+        out[j] += coeff * amp;
+    }
+    out
+}
+
+#[test]
+fn test_stabilizers_of_random_states() {
+    for seed in 0..20 {
+        let n = 2 + (seed as usize) % 4;
+        let ch_form = state(random_circuit_all_gates(n, 60, seed));
+        let psi = ch_form.to_statevector();
+        let stabilizers = ch_form.stabilizers();
+        assert_eq!(stabilizers.len(), n);
+        // The generators are independent and commute.
+        assert!(MixedStabilizerState::new(n, stabilizers.clone()).is_ok());
+        for pauli in &stabilizers {
+            let applied = apply_pauli(pauli, &psi);
+            for (a, b) in applied.iter().zip(&psi) {
+                assert!((a - b).norm() < 1e-8, "{} does not stabilize", pauli);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dirac_string() {
+    let cases = [
+        (
+            CliffordCircuit::builder(2).h(0).cx(0, 1),
+            "(|00> + |11>)/√2",
+        ),
+        (CliffordCircuit::builder(1).h(0).s(0), "(|0> + i|1>)/√2"),
+        (CliffordCircuit::builder(2).x(0), "|01>"),
+        (CliffordCircuit::builder(1).x(0).z(0), "-|1>"),
+        (
+            CliffordCircuit::builder(1).sqrt_y(0),
+            "e^{iπ/4}(|0> + |1>)/√2",
+        ),
+        (
+            CliffordCircuit::builder(3).h(0).h(1).h(2).cz(0, 1),
+            "(|000> + |001> + |010> - |011> + |100> + |101> + |110> - |111>)/(2√2)",
+        ),
+    ];
+    for (builder, expected) in cases {
+        assert_eq!(state(builder.build().unwrap()).to_dirac_string(), expected);
+    }
+
+    let mut ch_form = StabilizerCHForm::new(1);
+    ch_form.set_global_phase(Complex64::new(0.0, -1.0));
+    assert_eq!(ch_form.to_dirac_string(), "(0.0000-1.0000i)|0>");
+}
+
+#[test]
+fn test_display() {
+    let bell = state(CliffordCircuit::builder(2).h(0).cx(0, 1).build().unwrap());
+    assert_eq!(
+        bell.to_string(),
+        "stabilizers: +XX, +ZZ\nstate: (|00> + |11>)/√2"
+    );
+    assert_eq!(
+        state(CliffordCircuit::builder(2).x(0).build().unwrap()).to_string(),
+        "stabilizers: -ZI, +IZ\nstate: |01>"
+    );
+
+    let mut plus = StabilizerCHForm::new(5);
+    for q in 0..5 {
+        plus.apply_h(q);
+    }
+    assert!(
+        plus.to_string()
+            .ends_with("state: superposition of 2^5 basis states")
+    );
+
+    let internal = bell.get_internal_state().to_string();
+    assert!(internal.starts_with("--- CHFormInternalState (n=2) ---\n"));
+    assert!(internal.contains("|11>: +0.707107 +0.000000 i"));
+
+    // Both ket strings put qubit 0 on the right.
+    let flipped = state(CliffordCircuit::builder(2).x(0).build().unwrap());
+    assert!(
+        flipped
+            .get_internal_state()
+            .to_string()
+            .contains("|01>: +1.000000 +0.000000 i")
+    );
+}
@@ -0,0 +1,88 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use stabilizer_ch_form_rust::api::parser::from_qasm_str_dynamic;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_mid_circuit_measurement_and_feedforward() {
+    // Measure one half of a Bell pair and undo the correlation with a conditional X.
+    let mut circuit = DynamicCircuit::new(2, 3);
+    circuit.add_gate(CliffordGate::H(0));
+    circuit.add_gate(CliffordGate::CX(0, 1));
+    circuit.add_measure(0, 0);
+    circuit.add_conditional_gate(ClassicalCondition::bit(0, true), CliffordGate::X(1));
+    circuit.add_measure(1, 1);
+    circuit.add_reset(0);
+    circuit.add_measure(0, 2);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut seen = [false; 2];
+    for _ in 0..50 {
+        let result = circuit.execute_with_rng(&mut rng).unwrap();
+        let record = &result.classical_register;
+        seen[record[0] as usize] = true;
+        assert!(!record[1], "Conditional X did not undo the correlation");
+        assert!(!record[2], "Reset did not return the qubit to |0>");
+        assert!((result.state.to_statevector()[0].norm() - 1.0).abs() < 1e-10);
+    }
+    assert!(seen[0] && seen[1], "Both outcomes should occur");
+}
+
+#[test]
+fn test_measure_pauli() {
+    let mut bell = StabilizerCHForm::new(2);
+    bell.apply_h(0);
+    bell.apply_cx(0, 1);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for (pauli, expected) in [("ZZ", false), ("XX", false), ("YY", true), ("-YY", false)] {
+        let mut state = bell.clone();
+        let pauli: PauliString = pauli.parse().unwrap();
+        assert_eq!(state.measure_pauli_with_rng(&pauli, &mut rng), expected);
+        // A stabilizer measurement leaves the state unchanged.
+        assert!((state.inner_product(&bell).norm() - 1.0).abs() < 1e-8);
+    }
+
+    // Measuring XI on |00> is random and leaves an X eigenstate.
+    let mut state = StabilizerCHForm::new(2);
+    let xi: PauliString = "XI".parse().unwrap();
+    let outcome = state.measure_pauli_with_rng(&xi, &mut rng);
+    assert_eq!(state.measure_pauli_with_rng(&xi, &mut rng), outcome);
+}
+
+#[test]
+fn test_parse_dynamic_qasm() {
+    let qasm = r#"
+        OPENQASM 2.0;
+        include "qelib1.inc";
+        qreg q[2];
+        creg c[2];
+        h q[0];
+        measure q[0] -> c[0];
+        if(c==1) x q[1];
+        reset q[0];
+        measure q[1] -> c[1];
+    "#;
+    let circuit = from_qasm_str_dynamic(qasm).unwrap();
+    assert_eq!(circuit.n_clbits, 2);
+    assert_eq!(circuit.instructions.len(), 5);
+    assert_eq!(
+        circuit.instructions[2],
+        CircuitInstruction::Conditional {
+            condition: ClassicalCondition {
+                cbits: vec![0, 1],
+                value: 1,
+            },
+            gate: CliffordGate::X(1),
+        }
+    );
+
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..20 {
+        let record = circuit
+            .execute_with_rng(&mut rng)
+            .unwrap()
+            .classical_register;
+        assert_eq!(record[0], record[1]);
+    }
+}
@@ -0,0 +1,108 @@
+mod common;
+use common::random_circuit_all_gates;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn ghz(n: usize) -> StabilizerCHForm {
+    let mut state = StabilizerCHForm::new(n);
+    state.apply_h(0);
+    for q in 1..n {
+        state.apply_cx(0, q);
+    }
+    state
+}
+
+/// Returns -log2 tr(ρ_A^2), computed from the dense statevector.
+fn entropy_from_purity(state: &StabilizerCHForm, subsystem: &[usize]) -> f64 {
+    let n = state.n_qubits();
+    let psi = state.to_statevector();
+    let mask: usize = subsystem.iter().map(|&q| 1 << q).sum();
+    let dim = 1usize << n;
+    // ρ_A[a, a'] = Σ_b ψ(a b) ψ*(a' b), indexed by the masked basis indices.
+    let mut rho = vec![num_complex::Complex64::new(0.0, 0.0); dim * dim];
+    for i in 0..dim {
+        for j in 0..dim {
+            if i & !mask == j & !mask {
+                rho[(i & mask) * dim + (j & mask)] += psi[i] * psi[j].conj();
+            }
+        }
+    }
+    let purity: f64 = rho.iter().map(|value| value.norm_sqr()).sum();
+    -purity.log2()
+}
+
+#[test]
+fn test_known_states() {
+    let state = ghz(5);
+    assert_eq!(state.entanglement_entropy(&[0]).unwrap(), 1);
+    assert_eq!(state.entanglement_entropy(&[4, 1, 2]).unwrap(), 1);
+    assert_eq!(state.entanglement_entropy(&[]).unwrap(), 0);
+    assert_eq!(state.schmidt_rank(&[0, 1]).unwrap(), 2);
+    assert_eq!(state.mutual_information(&[0], &[1]).unwrap(), 1);
+    assert_eq!(state.mutual_information(&[0], &[1, 2, 3, 4]).unwrap(), 2);
+
+    // Bell pairs on (0, 2) and (1, 3).
+    let mut pairs = StabilizerCHForm::new(4);
+    for (a, b) in [(0, 2), (1, 3)] {
+        pairs.apply_h(a);
+        pairs.apply_cx(a, b);
+    }
+    assert_eq!(pairs.entanglement_entropy(&[0, 1]).unwrap(), 2);
+    assert_eq!(pairs.entanglement_entropy(&[0, 2]).unwrap(), 0);
+    assert_eq!(pairs.schmidt_rank(&[0, 1]).unwrap(), 4);
+    assert_eq!(pairs.mutual_information(&[0], &[2]).unwrap(), 2);
+    assert_eq!(pairs.mutual_information(&[0], &[1]).unwrap(), 0);
+
+    assert_eq!(
+        StabilizerCHForm::new(3).entanglement_entropy(&[1]).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_random_states_match_purity() {
+    let subsystems: [&[usize]; 4] = [&[0], &[1, 3], &[4, 0, 2], &[0, 1, 2, 3, 4]];
+    for seed in 0..10 {
+        let state = StabilizerCHForm::try_from(&random_circuit_all_gates(5, 80, seed)).unwrap();
+        for subsystem in subsystems {
+            let entropy = state.entanglement_entropy(subsystem).unwrap();
+            let expected = entropy_from_purity(&state, subsystem);
+            assert!((entropy as f64 - expected).abs() < 1e-8);
+            let complement: Vec<usize> = (0..5).filter(|q| !subsystem.contains(q)).collect();
+            assert_eq!(state.entanglement_entropy(&complement).unwrap(), entropy);
+            assert_eq!(
+                state
+                    .reduced_density_matrix(subsystem)
+                    .unwrap()
+                    .generators()
+                    .len(),
+                subsystem.len() - entropy
+            );
+        }
+    }
+}
+
+#[test]
+fn test_invalid_subsystems() {
+    let state = ghz(3);
+    assert_eq!(
+        state.entanglement_entropy(&[0, 0]),
+        Err("Qubit index 0 is repeated.".to_string())
+    );
+    assert_eq!(
+        state.schmidt_rank(&[3]),
+        Err("Qubit index 3 out of bounds.".to_string())
+    );
+    assert_eq!(
+        state.mutual_information(&[0, 1], &[1]),
+        Err("Qubit index 1 is in both subsystems.".to_string())
+    );
+    assert_eq!(
+        state.mutual_information(&[0], &[2, 2]),
+        Err("Qubit index 2 is repeated.".to_string())
+    );
+    // The messages match those of `partial_trace`.
+    assert_eq!(
+        state.partial_trace(&[0, 0]).unwrap_err(),
+        state.entanglement_entropy(&[0, 0]).unwrap_err()
+    );
+}
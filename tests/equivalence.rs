@@ -0,0 +1,71 @@
+mod common;
+use common::random_circuit_all_gates;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn build(n_qubits: usize, gates: &[CliffordGate]) -> CliffordCircuit {
+    let mut circuit = CliffordCircuit::new(n_qubits);
+    circuit.add_multiple_gates(gates.to_vec());
+    circuit
+}
+
+#[test]
+fn test_gate_identities() {
+    use CliffordGate::*;
+    // (lhs, rhs, equal including the global phase)
+    let cases = [
+        (vec![S(0), S(0)], vec![Z(0)], true),
+        (vec![H(0), Z(0), H(0)], vec![X(0)], true),
+        (vec![SqrtX(0), SqrtX(0)], vec![X(0)], true),
+        (vec![X(0), Z(0)], vec![Z(0), X(0)], false),
+        (vec![Z(0), X(0)], vec![Y(0)], false),
+        (vec![H(1), CX(0, 1), H(1)], vec![CZ(0, 1)], true),
+        (vec![CX(0, 1), CX(1, 0), CX(0, 1)], vec![Swap(0, 1)], true),
+        (vec![CX(0, 1), CX(1, 0)], vec![DCX(0, 1)], true),
+    ];
+    for (lhs, rhs, exact) in cases {
+        let (lhs, rhs) = (build(2, &lhs), build(2, &rhs));
+        assert!(
+            lhs.equivalent(&rhs, true).unwrap(),
+            "{:?} != {:?}",
+            lhs,
+            rhs
+        );
+        assert_eq!(lhs.equivalent(&rhs, false).unwrap(), exact, "{:?}", lhs);
+    }
+
+    assert!(
+        !build(1, &[H(0)])
+            .equivalent(&build(1, &[S(0)]), true)
+            .unwrap()
+    );
+    assert!(!build(1, &[]).equivalent(&build(2, &[]), true).unwrap());
+    assert!(build(1, &[H(1)]).equivalent(&build(1, &[]), true).is_err());
+}
+
+#[test]
+fn test_compiler_passes_are_equivalent() {
+    for seed in 0..5 {
+        let circuit = random_circuit_all_gates(4, 100, seed);
+        let identity = &circuit + &circuit.inverse();
+        assert!(
+            identity
+                .equivalent(&CliffordCircuit::new(4), false)
+                .unwrap()
+        );
+
+        let (optimized, _) = circuit.optimize().unwrap();
+        assert!(optimized.equivalent(&circuit, true).unwrap());
+        assert!(
+            circuit
+                .resynthesize()
+                .unwrap()
+                .equivalent(&circuit, true)
+                .unwrap()
+        );
+
+        // Dropping a gate breaks equivalence.
+        let mut broken = circuit.clone();
+        broken.gates.remove(50);
+        assert!(!broken.equivalent(&circuit, true).unwrap());
+    }
+}
@@ -59,3 +59,35 @@ fn compare_with_qiskit_references() {
         }
     }
 }
+
+#[test]
+fn parse_additional_gates() {
+    let qasm = r#"
+        OPENQASM 2.0;
+        qreg q[2];
+        cy q[0], q[1];
+        iswap q[0], q[1];
+        iswapdg q[1], q[0];
+        ecr q[0], q[1];
+        dcx q[1], q[0];
+        sy q[0];
+        sydg q[1];
+        h_xy q[0];
+        h_yz q[1];
+    "#;
+    let circuit = stabilizer_ch_form_rust::api::parser::from_qasm_str(qasm).unwrap();
+    assert_eq!(
+        circuit.gates,
+        vec![
+            CliffordGate::CY(0, 1),
+            CliffordGate::ISwap(0, 1),
+            CliffordGate::ISwapdg(1, 0),
+            CliffordGate::ECR(0, 1),
+            CliffordGate::DCX(1, 0),
+            CliffordGate::SqrtY(0),
+            CliffordGate::SqrtYdg(1),
+            CliffordGate::HXY(0),
+            CliffordGate::HYZ(1),
+        ]
+    );
+}
@@ -50,3 +50,122 @@ fn test_hadamard_behaviour() {
     let internal_state = ch_form.get_internal_state();
     internal_state.pretty_print();
 }
+
+/// Returns the unitary of `gate` acting on `n_qubits` qubits.
+fn gate_unitary(n_qubits: usize, gate: CliffordGate) -> ndarray::Array2<num_complex::Complex64> {
+    let mut circuit = CliffordCircuit::new(n_qubits);
+    circuit.add_gate(gate);
+    circuit.to_unitary().unwrap()
+}
+
+#[test]
+fn test_additional_gates_match_reference_matrices() {
+    use num_complex::Complex64 as C;
+    let o = C::new(0.0, 0.0);
+    let l = C::new(1.0, 0.0);
+    let i = C::new(0.0, 1.0);
+    let r = 1.0 / 2f64.sqrt();
+
+    // Matrices use the little-endian ordering of `to_statevector`, so the first
+    // argument of a two-qubit gate is the least significant bit.
+    let cases = vec![
+        (
+            CliffordGate::CY(0, 1),
+            ndarray::array![[l, o, o, o], [o, o, o, -i], [o, o, l, o], [o, i, o, o]],
+        ),
+        (
+            CliffordGate::ISwap(0, 1),
+            ndarray::array![[l, o, o, o], [o, o, i, o], [o, i, o, o], [o, o, o, l]],
+        ),
+        (
+            CliffordGate::ISwapdg(0, 1),
+            ndarray::array![[l, o, o, o], [o, o, -i, o], [o, -i, o, o], [o, o, o, l]],
+        ),
+        (
+            CliffordGate::ECR(0, 1),
+            ndarray::array![[o, l, o, i], [l, o, -i, o], [o, i, o, l], [-i, o, l, o]] * r,
+        ),
+        (
+            CliffordGate::DCX(0, 1),
+            ndarray::array![[l, o, o, o], [o, o, o, l], [o, l, o, o], [o, o, l, o]],
+        ),
+        (
+            CliffordGate::SqrtY(0),
+            ndarray::array![[l + i, -l - i], [l + i, l + i]] * 0.5,
+        ),
+        (
+            CliffordGate::SqrtYdg(0),
+            ndarray::array![[l - i, l - i], [-l + i, l - i]] * 0.5,
+        ),
+        (
+            CliffordGate::HXY(0),
+            ndarray::array![[o, l - i], [l + i, o]] * r,
+        ),
+        (CliffordGate::HYZ(0), ndarray::array![[l, -i], [i, -l]] * r),
+    ];
+
+    for (gate, expected) in cases {
+        let n_qubits = gate.qubits().len();
+        let actual = gate_unitary(n_qubits, gate.clone());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!(
+                (a - e).norm() < 1e-8,
+                "{:?}: expected {:?}, got {:?}",
+                gate,
+                expected,
+                actual
+            );
+        }
+    }
+}
+
+#[test]
+fn test_additional_gate_apis() {
+    let mut ch_form = StabilizerCHForm::new(3);
+    ch_form.apply_h(0);
+    ch_form.apply_cy(0, 1);
+    ch_form.apply_iswap(1, 2);
+    ch_form.apply_ecr(2, 0);
+    ch_form.apply_dcx(0, 1);
+    ch_form.apply_sqrt_y(2);
+    ch_form.apply_h_xy(1);
+    ch_form.apply_h_yz(0);
+    // Undo everything in reverse order.
+    ch_form.apply_h_yz(0);
+    ch_form.apply_h_xy(1);
+    ch_form.apply_sqrt_ydg(2);
+    ch_form.apply_dcx(1, 0);
+    ch_form.apply_ecr(2, 0);
+    ch_form.apply_iswapdg(1, 2);
+    ch_form.apply_cy(0, 1);
+    ch_form.apply_h(0);
+
+    let statevec = ch_form.to_statevector();
+    assert!((statevec[0] - num_complex::Complex64::new(1.0, 0.0)).norm() < 1e-8);
+}
+
+#[test]
+fn test_two_qubit_gates_after_random_clifford() {
+    // On random states U_C is non-trivial, so the CH-form rules are checked
+    // against the dense gate unitary including the global phase.
+    for seed in 0..5 {
+        let base = common::random_circuit(4, 40, Some(seed));
+        let base_statevec = StabilizerCHForm::try_from(&base).unwrap().to_statevector();
+        for gate in [
+            CliffordGate::CY(2, 0),
+            CliffordGate::DCX(1, 3),
+            CliffordGate::ISwap(3, 1),
+            CliffordGate::ISwapdg(0, 2),
+            CliffordGate::ECR(0, 2),
+            CliffordGate::Swap(1, 2),
+        ] {
+            let mut circuit = base.clone();
+            circuit.add_gate(gate.clone());
+            let actual = StabilizerCHForm::try_from(&circuit)
+                .unwrap()
+                .to_statevector();
+            let expected = gate_unitary(4, gate).dot(&base_statevec);
+            common::assert_eq_complex_array1(&actual, &expected);
+        }
+    }
+}
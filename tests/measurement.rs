@@ -27,3 +27,45 @@ fn test_project() {
 
     dbg!(&ch_form.to_statevector());
 }
+
+#[test]
+fn test_measure_x_and_y_bases() {
+    for _ in 0..20 {
+        let mut ch_form = StabilizerCHForm::new(2);
+        ch_form.apply_h(0);
+        ch_form.apply_cx(0, 1);
+        // XX = +1 and YY = -1 on the Bell state.
+        let outcome = ch_form.measure_x(0);
+        assert_eq!(ch_form.measure_x(1), outcome);
+        assert_eq!(ch_form.measure_in_basis(0, Basis::X), outcome);
+
+        let mut ch_form = StabilizerCHForm::new(2);
+        ch_form.apply_h(0);
+        ch_form.apply_cx(0, 1);
+        let outcome = ch_form.measure_y(0);
+        assert_eq!(ch_form.measure_y(1), !outcome);
+    }
+}
+
+#[test]
+fn test_project_x_and_y_bases() {
+    let s = 1.0 / 2f64.sqrt();
+
+    // |0> projected onto |->.
+    let mut ch_form = StabilizerCHForm::new(1);
+    ch_form.project_x(0, true).unwrap();
+    let sv = ch_form.to_statevector();
+    assert!((sv[0].norm() - s).abs() < 1e-8);
+    assert!((sv[0] + sv[1]).norm() < 1e-8);
+
+    // |+i> cannot be projected onto |-i>, and the state is left unchanged.
+    let mut ch_form = StabilizerCHForm::new(1);
+    ch_form.apply_h(0);
+    ch_form.apply_s(0);
+    let before = ch_form.to_statevector();
+    assert!(ch_form.project_y(0, true).is_err());
+    assert!(ch_form.project_in_basis(0, Basis::Y, false).is_ok());
+    let after = ch_form.to_statevector();
+    assert!((&before - &after).iter().all(|d| d.norm() < 1e-8));
+    assert!((after[1] - after[0] * num_complex::Complex64::i()).norm() < 1e-8);
+}
@@ -0,0 +1,96 @@
+mod common;
+use common::random_circuit_all_gates;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_redundant_runs_are_removed() {
+    let circuit = CliffordCircuit::builder(3)
+        .h(0)
+        .h(0)
+        .s(1)
+        .s(1)
+        .s(1)
+        .s(1)
+        .cx(0, 1)
+        .cx(0, 1)
+        .x(2)
+        .z(2)
+        .x(2)
+        .build()
+        .unwrap();
+    let (optimized, report) = circuit.optimize().unwrap();
+    assert_eq!(optimized.gates, vec![CliffordGate::Z(2)]);
+    assert_eq!(
+        report,
+        OptimizationReport {
+            gate_count_before: 11,
+            gate_count_after: 1,
+            depth_before: 6,
+            depth_after: 1,
+        }
+    );
+}
+
+#[test]
+fn test_cancellation_through_commuting_gates() {
+    let cases = [
+        (
+            CliffordCircuit::builder(2).z(0).cx(0, 1).z(0).build(),
+            vec![CliffordGate::CX(0, 1)],
+        ),
+        (
+            CliffordCircuit::builder(2).x(1).cx(0, 1).x(1).build(),
+            vec![CliffordGate::CX(0, 1)],
+        ),
+        (
+            CliffordCircuit::builder(3).cz(0, 1).h(2).cz(1, 0).build(),
+            vec![CliffordGate::H(2)],
+        ),
+        (
+            CliffordCircuit::builder(3)
+                .cx(0, 1)
+                .cx(0, 2)
+                .cx(0, 1)
+                .build(),
+            vec![CliffordGate::CX(0, 2)],
+        ),
+    ];
+    for (circuit, expected) in cases {
+        assert_eq!(circuit.unwrap().optimize().unwrap().0.gates, expected);
+    }
+
+    // A CX does not commute with H on its control.
+    let circuit = CliffordCircuit::builder(2)
+        .cx(0, 1)
+        .h(0)
+        .cx(0, 1)
+        .build()
+        .unwrap();
+    assert_eq!(circuit.optimize().unwrap().0.gates, circuit.gates);
+}
+
+#[test]
+fn test_optimized_circuits_are_equivalent() {
+    for seed in 0..10 {
+        let circuit = random_circuit_all_gates(4, 200, seed);
+        let (optimized, report) = circuit.optimize().unwrap();
+        assert!(report.gate_count_after < report.gate_count_before);
+        assert_eq!(report.gate_count_after, optimized.gates.len());
+        assert_eq!(report.depth_after, optimized.depth());
+
+        assert_eq!(
+            CliffordTableau::from_circuit(&optimized).unwrap(),
+            CliffordTableau::from_circuit(&circuit).unwrap()
+        );
+        let expected = StabilizerCHForm::try_from(&circuit).unwrap();
+        let actual = StabilizerCHForm::try_from(&optimized).unwrap();
+        assert!((expected.inner_product(&actual).norm() - 1.0).abs() < 1e-8);
+
+        let identity = &circuit + &circuit.inverse();
+        assert!(identity.optimize().unwrap().0.gates.is_empty());
+    }
+
+    let mut invalid = CliffordCircuit::new(1);
+    invalid.apply_h(1);
+    assert!(invalid.optimize().is_err());
+}
@@ -0,0 +1,74 @@
+mod common;
+use common::random_circuit_all_gates;
+use ndarray::Array1;
+use num_complex::Complex64;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use stabilizer_ch_form_rust::prelude::*;
+
+/// Returns the amplitudes of `psi` with qubit `qarg` fixed to `value`, with the
+/// remaining qubits shifted down.
+fn slice_qubit(psi: &Array1<Complex64>, qarg: usize, value: bool) -> Array1<Complex64> {
+    let low = (1usize << qarg) - 1;
+    (0..psi.len() / 2)
+        .map(|i| psi[(i & low) | ((i & !low) << 1) | ((value as usize) << qarg)])
+        .collect()
+}
+
+#[test]
+fn test_partial_trace_of_entangled_qubits() {
+    let mut state = StabilizerCHForm::new(3);
+    state.apply_h(0);
+    state.apply_cx(0, 1);
+    state.apply_x(2);
+
+    let traced = state.partial_trace(&[1]).unwrap();
+    assert_eq!(traced.n_qubits(), 2);
+    assert_eq!(traced.generators().len(), 1);
+    assert_eq!(traced.generators()[0].to_string(), "-IZ");
+    assert!(state.partial_trace(&[0, 1]).unwrap().is_pure());
+    assert!(state.partial_trace(&[1, 1]).is_err());
+    assert!(state.partial_trace(&[3]).is_err());
+
+    for seed in 0..10 {
+        let state = StabilizerCHForm::try_from(&random_circuit_all_gates(4, 60, seed)).unwrap();
+        assert_eq!(
+            state.partial_trace(&[3, 1]).unwrap(),
+            state.reduced_density_matrix(&[0, 2]).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_discard_measured() {
+    for seed in 0..20 {
+        let state = StabilizerCHForm::try_from(&random_circuit_all_gates(4, 60, seed)).unwrap();
+        for qarg in 0..4 {
+            let mut discarded = state.clone();
+            let outcome = discarded
+                .discard_measured_with_rng(qarg, &mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert_eq!(discarded.n_qubits(), 3);
+
+            let mut measured = state.clone();
+            let expected_outcome =
+                measured.measure_with_rng(qarg, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(outcome, expected_outcome);
+            let expected = slice_qubit(&measured.to_statevector(), qarg, outcome);
+            let overlap: Complex64 = expected
+                .iter()
+                .zip(&discarded.to_statevector())
+                .map(|(a, b)| a.conj() * b)
+                .sum();
+            assert!((overlap.norm() - 1.0).abs() < 1e-8, "overlap {}", overlap);
+        }
+    }
+}
+
+#[test]
+fn test_discard_measured_last_qubit() {
+    let mut state = StabilizerCHForm::new(1);
+    state.apply_h(0);
+    assert!(state.discard_measured(0).is_err());
+    assert_eq!(state.n_qubits(), 1);
+}
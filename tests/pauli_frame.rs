@@ -0,0 +1,122 @@
+mod common;
+use common::random_circuit;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_noiseless_ghz_samples() {
+    let mut circuit = CliffordCircuit::new(4);
+    circuit.apply_h(0);
+    circuit.apply_cx(0, 1);
+    circuit.apply_cx(1, 2);
+    circuit.apply_cx(2, 3);
+
+    let simulator = PauliFrameSimulator::new(&circuit, NoiseModel::noiseless()).unwrap();
+    let mut rng = StdRng::seed_from_u64(7);
+    let samples = simulator.sample(1000, &mut rng);
+
+    assert_eq!(samples.dim(), (1000, 4));
+    let mut n_ones = 0;
+    for row in samples.rows() {
+        assert!(
+            row.iter().all(|&b| b == row[0]),
+            "GHZ sample not correlated"
+        );
+        if row[0] {
+            n_ones += 1;
+        }
+    }
+    assert!(
+        n_ones > 400 && n_ones < 600,
+        "Unbalanced GHZ samples: {}",
+        n_ones
+    );
+}
+
+#[test]
+fn test_noiseless_samples_lie_in_support() {
+    let n_qubits = 5;
+    for seed in 0..10 {
+        let circuit = random_circuit(n_qubits, 60, Some(seed));
+        let statevector = StabilizerCHForm::try_from(&circuit)
+            .unwrap()
+            .to_statevector();
+
+        let simulator = PauliFrameSimulator::new(&circuit, NoiseModel::noiseless()).unwrap();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let samples = simulator.sample(200, &mut rng);
+
+        for row in samples.rows() {
+            let index = row
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (q, &b)| if b { acc | (1 << q) } else { acc });
+            assert!(
+                statevector[index].norm() > 1e-8,
+                "Sampled basis state {} has zero amplitude",
+                index
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deterministic_noise() {
+    let mut circuit = CliffordCircuit::new(2);
+    circuit.apply_x(0);
+
+    // A certain X error after the X gate cancels it.
+    let noise = NoiseModel {
+        gate_error: PauliChannel::new(1.0, 0.0, 0.0).unwrap(),
+        measurement_error: 0.0,
+    };
+    let simulator = PauliFrameSimulator::new(&circuit, noise).unwrap();
+    assert_eq!(simulator.reference_sample(), &[true, false]);
+    let samples = simulator.sample(100, &mut StdRng::seed_from_u64(1));
+    assert!(samples.rows().into_iter().all(|row| !row[0] && !row[1]));
+
+    // A certain measurement error flips every outcome.
+    let noise = NoiseModel {
+        gate_error: PauliChannel::default(),
+        measurement_error: 1.0,
+    };
+    let simulator = PauliFrameSimulator::new(&circuit, noise).unwrap();
+    let samples = simulator.sample(100, &mut StdRng::seed_from_u64(1));
+    assert!(samples.rows().into_iter().all(|row| !row[0] && row[1]));
+}
+
+#[test]
+fn test_noiseless_samples_with_all_gates() {
+    let mut circuit = CliffordCircuit::new(4);
+    circuit.add_multiple_gates(vec![
+        CliffordGate::H(0),
+        CliffordGate::SqrtY(1),
+        CliffordGate::HXY(2),
+        CliffordGate::HYZ(3),
+        CliffordGate::CY(0, 2),
+        CliffordGate::ISwap(1, 3),
+        CliffordGate::ECR(2, 1),
+        CliffordGate::DCX(3, 0),
+        CliffordGate::ISwapdg(0, 1),
+        CliffordGate::SqrtYdg(2),
+        CliffordGate::SqrtX(3),
+    ]);
+    let statevector = StabilizerCHForm::try_from(&circuit)
+        .unwrap()
+        .to_statevector();
+
+    let simulator = PauliFrameSimulator::new(&circuit, NoiseModel::noiseless()).unwrap();
+    let samples = simulator.sample(500, &mut StdRng::seed_from_u64(9));
+    let mut seen = [false; 16];
+    for row in samples.rows() {
+        let index = row
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (q, &b)| if b { acc | (1 << q) } else { acc });
+        seen[index] = true;
+    }
+    for (index, amp) in statevector.iter().enumerate() {
+        assert_eq!(seen[index], amp.norm() > 1e-8, "Mismatch at {}", index);
+    }
+}
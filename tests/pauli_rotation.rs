@@ -0,0 +1,62 @@
+mod common;
+use common::{assert_eq_complex_array1, random_circuit};
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_apply_pauli() {
+    let base = random_circuit(3, 30, Some(1));
+    let state = StabilizerCHForm::try_from(&base).unwrap();
+
+    let mut actual = state.clone();
+    actual.apply_pauli(&"-XYZ".parse().unwrap());
+
+    let mut expected = state.clone();
+    expected.apply_x(0);
+    expected.apply_y(1);
+    expected.apply_z(2);
+    assert_eq_complex_array1(&actual.to_statevector(), &(-expected.to_statevector()));
+}
+
+#[test]
+fn test_pauli_rotation_matches_definition() {
+    let r = 1.0 / 2f64.sqrt();
+    let paulis = ["ZII", "IXI", "-YIZ", "XYZ", "-ZZX", "III", "-III"];
+    for seed in 0..5 {
+        let state = StabilizerCHForm::try_from(&random_circuit(3, 30, Some(seed))).unwrap();
+        for pauli in paulis {
+            let pauli: PauliString = pauli.parse().unwrap();
+            let mut p_state = state.clone();
+            p_state.apply_pauli(&pauli);
+
+            for (negative, sign) in [(false, 1.0), (true, -1.0)] {
+                // exp(±iπ/4 P) = (I ± iP)/√2
+                let expected = (state.to_statevector()
+                    + p_state.to_statevector() * Complex64::new(0.0, sign))
+                    * r;
+                let mut actual = state.clone();
+                actual.apply_pauli_rotation(&pauli, negative);
+                assert_eq_complex_array1(&actual.to_statevector(), &expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pauli_based_computation() {
+    // H = exp(-iπ/4 Z) exp(-iπ/4 X) exp(-iπ/4 Z) and
+    // CZ = exp(-iπ/4 ZI) exp(-iπ/4 IZ) exp(iπ/4 ZZ), both up to a global phase.
+    let mut state = StabilizerCHForm::new(2);
+    for pauli in ["ZI", "XI", "ZI", "IZ", "IX", "IZ"] {
+        state.apply_pauli_rotation(&pauli.parse().unwrap(), true);
+    }
+    state.apply_pauli_rotation(&"ZI".parse().unwrap(), true);
+    state.apply_pauli_rotation(&"IZ".parse().unwrap(), true);
+    state.apply_pauli_rotation(&"ZZ".parse().unwrap(), false);
+
+    let mut graph_state = StabilizerCHForm::new(2);
+    graph_state.apply_h(0);
+    graph_state.apply_h(1);
+    graph_state.apply_cz(0, 1);
+    assert!((state.inner_product(&graph_state).norm() - 1.0).abs() < 1e-8);
+}
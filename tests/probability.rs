@@ -0,0 +1,95 @@
+mod common;
+use common::random_circuit;
+use stabilizer_ch_form_rust::prelude::*;
+
+/// Computes the marginal distribution of `qubits` from the dense statevector.
+fn naive_marginal(ch_form: &StabilizerCHForm, qubits: &[usize]) -> Vec<f64> {
+    let mut distribution = vec![0.0; 1 << qubits.len()];
+    for (i, amp) in ch_form.to_statevector().iter().enumerate() {
+        let index = qubits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (j, &q)| acc | (((i >> q) & 1) << j));
+        distribution[index] += amp.norm_sqr();
+    }
+    distribution
+}
+
+#[test]
+fn test_marginal_distribution_random_circuits() {
+    let n_qubits = 5;
+    for seed in 0..20 {
+        let circuit = random_circuit(n_qubits, 60, Some(seed));
+        let ch_form = StabilizerCHForm::try_from(&circuit).unwrap();
+
+        for qubits in [vec![0], vec![3, 1], vec![4, 0, 2], vec![0, 1, 2, 3, 4]] {
+            let expected = naive_marginal(&ch_form, &qubits);
+            let actual = ch_form.marginal_distribution(&qubits);
+            for (e, a) in expected.iter().zip(&actual) {
+                assert!(
+                    (e - a).abs() < 1e-8,
+                    "Expected {:?}, got {:?}",
+                    expected,
+                    actual
+                );
+            }
+
+            // `probability_of` agrees with every entry of the marginal.
+            for (index, p) in actual.iter().enumerate() {
+                let assignment: Vec<(usize, bool)> = qubits
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &q)| (q, (index >> j) & 1 == 1))
+                    .collect();
+                assert_eq!(ch_form.probability_of(&assignment), *p);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_probability_does_not_collapse() {
+    let mut ch_form = StabilizerCHForm::new(2);
+    ch_form.apply_h(0);
+    ch_form.apply_cx(0, 1);
+    ch_form.apply_x(1);
+
+    assert_eq!(ch_form.probability(0, true), 0.5);
+    assert_eq!(ch_form.probability_of(&[(0, true), (1, false)]), 0.5);
+    assert_eq!(ch_form.probability_of(&[(0, true), (1, true)]), 0.0);
+    assert_eq!(
+        ch_form.marginal_distribution(&[0, 1]),
+        vec![0.0, 0.5, 0.5, 0.0]
+    );
+    // The state is still entangled.
+    assert_eq!(ch_form.probability(1, false), 0.5);
+}
+
+#[test]
+fn test_probability_of_repeated_qubits_and_all_gates() {
+    let mut ghz = StabilizerCHForm::new(3);
+    ghz.apply_h(0);
+    ghz.apply_cx(0, 1);
+    ghz.apply_cx(1, 2);
+    assert_eq!(ghz.probability_of(&[(0, true), (0, true)]), 0.5);
+    assert_eq!(ghz.probability_of(&[(0, true), (0, false)]), 0.0);
+    assert_eq!(ghz.probability_of(&[(0, true), (2, false)]), 0.0);
+    assert_eq!(ghz.probability_of(&[]), 1.0);
+    assert_eq!(ghz.marginal_distribution(&[2, 2]), vec![0.5, 0.0, 0.0, 0.5]);
+
+    for seed in 0..10 {
+        let circuit = common::random_circuit_all_gates(6, 80, seed);
+        let ch_form = StabilizerCHForm::try_from(&circuit).unwrap();
+        let qubits = [5, 0, 3, 1];
+        let expected = naive_marginal(&ch_form, &qubits);
+        let actual = ch_form.marginal_distribution(&qubits);
+        for (e, a) in expected.iter().zip(&actual) {
+            assert!(
+                (e - a).abs() < 1e-8,
+                "Expected {:?}, got {:?}",
+                expected,
+                actual
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use stabilizer_ch_form_rust::prelude::*;
+use std::collections::HashMap;
+
+#[test]
+fn test_random_clifford_is_reproducible() {
+    for n in 1..8 {
+        let tableau = CliffordTableau::random(n, &mut StdRng::seed_from_u64(n as u64));
+        let again = CliffordTableau::random(n, &mut StdRng::seed_from_u64(n as u64));
+        assert_eq!(tableau, again);
+        // `new` re-checks the symplectic condition.
+        assert!(
+            CliffordTableau::new(tableau.symplectic().clone(), tableau.phases().clone()).is_ok()
+        );
+
+        let circuit = CliffordCircuit::random(n, &mut StdRng::seed_from_u64(n as u64));
+        assert_eq!(CliffordTableau::from_circuit(&circuit).unwrap(), tableau);
+
+        let state = StabilizerCHForm::random(n, &mut StdRng::seed_from_u64(n as u64));
+        let norm: f64 = state.to_statevector().iter().map(|a| a.norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn test_random_single_qubit_clifford_is_uniform() {
+    let mut rng = StdRng::seed_from_u64(2024);
+    let mut counts: HashMap<SingleQubitClifford, usize> = HashMap::new();
+    for _ in 0..12_000 {
+        let tableau = CliffordTableau::random(1, &mut rng);
+        let image = |p: PauliString| (p.paulis[0], p.negative);
+        let element = SingleQubitClifford::from_conjugation(
+            image(tableau.destabilizer(0)),
+            image(tableau.stabilizer(0)),
+        )
+        .unwrap();
+        *counts.entry(element).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 24);
+    for count in counts.values() {
+        assert!((400..600).contains(count), "Non-uniform count {}", count);
+    }
+}
+
+#[test]
+fn test_random_two_qubit_states_cover_all_stabilizer_states() {
+    // There are 60 two-qubit stabilizer states, each with probability 1/60.
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut counts: HashMap<Vec<(i64, i64)>, usize> = HashMap::new();
+    for _ in 0..3000 {
+        let statevector = StabilizerCHForm::random(2, &mut rng).to_statevector();
+        // Fix the global phase so that the first non-zero amplitude is positive.
+        let first = statevector.iter().find(|a| a.norm() > 1e-8).unwrap();
+        let phase = first / first.norm();
+        let key = statevector
+            .iter()
+            .map(|a| {
+                let a = a / phase * 1e6;
+                (a.re.round() as i64, a.im.round() as i64)
+            })
+            .collect();
+        *counts.entry(key).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 60);
+    for count in counts.values() {
+        assert!((25..80).contains(count), "Non-uniform count {}", count);
+    }
+}
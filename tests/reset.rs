@@ -0,0 +1,72 @@
+mod common;
+use common::assert_eq_complex_array1;
+use ndarray::array;
+use num_complex::Complex64;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_reset_entangled_qubit() {
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..20 {
+        let mut ch_form = StabilizerCHForm::new(3);
+        ch_form.apply_h(0);
+        ch_form.apply_cx(0, 1);
+        ch_form.apply_cx(1, 2);
+
+        ch_form.reset_with_rng(1, &mut rng);
+
+        // Qubit 1 is |0> and qubits 0 and 2 are still correlated.
+        let outcome = ch_form.measure_with_rng(0, &mut rng);
+        assert!(!ch_form.measure_with_rng(1, &mut rng));
+        assert_eq!(ch_form.measure_with_rng(2, &mut rng), outcome);
+    }
+}
+
+#[test]
+fn test_reset_ancilla_reuse() {
+    // Extract the ZZ parity of |10> twice with the same ancilla.
+    let mut rng = StdRng::seed_from_u64(5);
+    let mut ch_form = StabilizerCHForm::new(3);
+    ch_form.apply_x(0);
+    for _ in 0..2 {
+        ch_form.reset_with_rng(2, &mut rng);
+        ch_form.apply_cx(0, 2);
+        ch_form.apply_cx(1, 2);
+        assert!(ch_form.measure_with_rng(2, &mut rng));
+    }
+}
+
+#[test]
+fn test_reset_x_and_y() {
+    let s = 1.0 / 2f64.sqrt();
+
+    let mut ch_form = StabilizerCHForm::new(1);
+    ch_form.apply_h(0);
+    ch_form.apply_s(0);
+    ch_form.reset_x(0);
+    let sv = ch_form.to_statevector() / ch_form.to_statevector()[0] * s;
+    assert_eq_complex_array1(&sv, &array![Complex64::new(s, 0.0), Complex64::new(s, 0.0)]);
+
+    let mut ch_form = StabilizerCHForm::new(1);
+    ch_form.apply_x(0);
+    ch_form.reset_y(0);
+    let sv = ch_form.to_statevector() / ch_form.to_statevector()[0] * s;
+    assert_eq_complex_array1(&sv, &array![Complex64::new(s, 0.0), Complex64::new(0.0, s)]);
+}
+
+#[test]
+fn test_reset_in_basis_with_rng() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for basis in [Basis::X, Basis::Y, Basis::Z] {
+        for _ in 0..10 {
+            let mut ch_form = StabilizerCHForm::new(2);
+            ch_form.apply_h(0);
+            ch_form.apply_cx(0, 1);
+
+            ch_form.reset_in_basis_with_rng(0, basis, &mut rng);
+            assert!(!ch_form.measure_in_basis_with_rng(0, basis, &mut rng));
+        }
+    }
+}
@@ -0,0 +1,76 @@
+mod common;
+use common::random_circuit_all_gates;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn in_gate_set(gate: &CliffordGate, gate_set: GateSet) -> bool {
+    match gate_set {
+        GateSet::Standard => matches!(
+            gate,
+            CliffordGate::H(_)
+                | CliffordGate::S(_)
+                | CliffordGate::Sdg(_)
+                | CliffordGate::X(_)
+                | CliffordGate::Y(_)
+                | CliffordGate::Z(_)
+                | CliffordGate::CX(..)
+        ),
+        GateSet::HSCX => matches!(
+            gate,
+            CliffordGate::H(_) | CliffordGate::S(_) | CliffordGate::CX(..)
+        ),
+        GateSet::HSCZ => matches!(
+            gate,
+            CliffordGate::H(_) | CliffordGate::S(_) | CliffordGate::CZ(..)
+        ),
+        GateSet::SqrtXSCX => matches!(
+            gate,
+            CliffordGate::SqrtX(_) | CliffordGate::S(_) | CliffordGate::CX(..)
+        ),
+    }
+}
+
+#[test]
+fn test_resynthesis_targets_gate_sets() {
+    let gate_sets = [
+        GateSet::Standard,
+        GateSet::HSCX,
+        GateSet::HSCZ,
+        GateSet::SqrtXSCX,
+    ];
+    for seed in 0..5 {
+        let circuit = random_circuit_all_gates(4, 300, seed);
+        let tableau = CliffordTableau::from_circuit(&circuit).unwrap();
+        let state = StabilizerCHForm::try_from(&circuit).unwrap();
+        for gate_set in gate_sets {
+            let resynthesized = circuit.resynthesize_with(gate_set).unwrap();
+            assert!(
+                resynthesized.gates.iter().all(|g| in_gate_set(g, gate_set)),
+                "{:?} contains gates outside {:?}",
+                resynthesized.gates,
+                gate_set
+            );
+            assert_eq!(
+                CliffordTableau::from_circuit(&resynthesized).unwrap(),
+                tableau
+            );
+            let actual = StabilizerCHForm::try_from(&resynthesized).unwrap();
+            assert!((state.inner_product(&actual).norm() - 1.0).abs() < 1e-8);
+        }
+    }
+}
+
+#[test]
+fn test_long_circuits_compress() {
+    let n = 6;
+    let circuit = random_circuit_all_gates(n, 5000, 42);
+    let resynthesized = circuit.resynthesize().unwrap();
+    // Aaronson-Gottesman uses O(n^2) gates.
+    assert!(resynthesized.gates.len() < 4 * n * n);
+    assert_eq!(
+        CliffordTableau::from_circuit(&resynthesized).unwrap(),
+        CliffordTableau::from_circuit(&circuit).unwrap()
+    );
+
+    let identity = &circuit + &circuit.inverse();
+    assert!(identity.resynthesize().unwrap().gates.is_empty());
+}
@@ -0,0 +1,101 @@
+mod common;
+use common::random_circuit_all_gates;
+use stabilizer_ch_form_rust::prelude::*;
+
+fn assert_routed(circuit: &CliffordCircuit, coupling_map: &CouplingMap) {
+    let (routed, final_layout) = circuit.route(coupling_map).unwrap();
+    assert_eq!(routed.n_qubits, coupling_map.n_qubits());
+    for gate in &routed.gates {
+        if let [a, b] = gate.qubits()[..] {
+            assert!(coupling_map.is_adjacent(a, b), "{:?} is not adjacent", gate);
+        }
+    }
+
+    let mut padded = circuit.clone();
+    padded.n_qubits = coupling_map.n_qubits();
+    let expected = StabilizerCHForm::try_from(&padded).unwrap();
+    let actual = StabilizerCHForm::try_from(&routed)
+        .unwrap()
+        .permuted(&final_layout);
+    let overlap = actual.inner_product(&expected);
+    assert!(
+        (overlap.re - 1.0).abs() < 1e-8 && overlap.im.abs() < 1e-8,
+        "overlap {}",
+        overlap
+    );
+}
+
+#[test]
+fn test_coupling_map() {
+    let line = CouplingMap::line(4);
+    assert_eq!(line.edges(), &[(0, 1), (1, 2), (2, 3)]);
+    assert!(line.is_adjacent(2, 1));
+    assert_eq!(line.distance(0, 3), Some(3));
+
+    let grid = CouplingMap::grid(2, 3);
+    assert_eq!(grid.distance(0, 5), Some(3));
+    assert_eq!(grid.neighbors(1).collect::<Vec<_>>(), vec![0, 2, 4]);
+    assert_eq!(CouplingMap::ring(5).distance(0, 4), Some(1));
+
+    let split = CouplingMap::new(4, &[(0, 1), (3, 2), (2, 3)]).unwrap();
+    assert_eq!(split.edges(), &[(0, 1), (2, 3)]);
+    assert_eq!(split.distance(0, 2), None);
+    assert!(CouplingMap::new(2, &[(0, 2)]).is_err());
+    assert!(CouplingMap::new(2, &[(1, 1)]).is_err());
+}
+
+#[test]
+fn test_route_preserves_state() {
+    for seed in 0..10 {
+        let circuit = random_circuit_all_gates(5, 80, seed);
+        assert_routed(&circuit, &CouplingMap::line(5));
+        assert_routed(&circuit, &CouplingMap::ring(5));
+        assert_routed(&circuit, &CouplingMap::grid(2, 3));
+    }
+}
+
+#[test]
+fn test_route_swaps_and_layout() {
+    // Adjacent gates are only relabelled.
+    let mut circuit = CliffordCircuit::new(3);
+    circuit.apply_h(0);
+    circuit.apply_cx(0, 1);
+    let (routed, layout) = circuit.route(&CouplingMap::line(3)).unwrap();
+    assert_eq!(routed.gates, circuit.gates);
+    assert_eq!(layout, vec![0, 1, 2]);
+
+    // A distant gate moves its first qubit next to the second.
+    let mut circuit = CliffordCircuit::new(4);
+    circuit.apply_cz(0, 3);
+    let (routed, layout) = circuit.route(&CouplingMap::line(4)).unwrap();
+    assert_eq!(
+        routed.gates,
+        vec![
+            CliffordGate::Swap(0, 1),
+            CliffordGate::Swap(1, 2),
+            CliffordGate::CZ(2, 3),
+        ]
+    );
+    assert_eq!(layout, vec![2, 0, 1, 3]);
+
+    // Logical swaps only change the layout.
+    let mut circuit = CliffordCircuit::new(2);
+    circuit.apply_swap(0, 1);
+    circuit.apply_x(0);
+    let (routed, layout) = circuit.route(&CouplingMap::line(2)).unwrap();
+    assert_eq!(routed.gates, vec![CliffordGate::X(1)]);
+    assert_eq!(layout, vec![1, 0]);
+}
+
+#[test]
+fn test_route_errors() {
+    let mut circuit = CliffordCircuit::new(4);
+    circuit.apply_cx(0, 3);
+    let split = CouplingMap::new(4, &[(0, 1), (2, 3)]).unwrap();
+    assert!(circuit.route(&split).is_err());
+    assert!(circuit.route(&CouplingMap::line(3)).is_err());
+
+    let mut invalid = CliffordCircuit::new(2);
+    invalid.apply_cx(0, 0);
+    assert!(invalid.route(&CouplingMap::line(2)).is_err());
+}
@@ -0,0 +1,90 @@
+mod common;
+use common::{assert_eq_complex_array1, random_circuit};
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_group_structure() {
+    let all = SingleQubitClifford::all();
+    assert_eq!(all.len(), 24);
+    for (i, a) in all.iter().enumerate() {
+        assert!(
+            all[i + 1..].iter().all(|b| a != b),
+            "Elements must be distinct"
+        );
+        assert_eq!(a.compose(&a.inverse()), SingleQubitClifford::IDENTITY);
+        assert_eq!(a.inverse().compose(a), SingleQubitClifford::IDENTITY);
+        for b in &all {
+            assert!(all.contains(&a.compose(b)));
+        }
+        assert!(
+            a.to_gates(0)
+                .iter()
+                .filter(|g| matches!(g, CliffordGate::H(_)))
+                .count()
+                <= 1
+        );
+        assert_eq!(SingleQubitClifford::from_gates(&a.to_gates(3)).unwrap(), *a);
+    }
+
+    let s = SingleQubitClifford::from_gates(&[CliffordGate::S(0)]).unwrap();
+    assert_eq!(s.conjugate(Pauli::Y), (Pauli::X, true));
+    let h = SingleQubitClifford::from_gates(&[CliffordGate::H(0)]).unwrap();
+    assert_eq!(h.conjugate(Pauli::Y), (Pauli::Y, true));
+    assert_eq!(
+        s.compose(&s),
+        SingleQubitClifford::from_gates(&[CliffordGate::Z(0)]).unwrap()
+    );
+
+    assert!(SingleQubitClifford::from_gates(&[CliffordGate::CX(0, 1)]).is_err());
+    assert!(SingleQubitClifford::from_gates(&[CliffordGate::H(0), CliffordGate::S(1)]).is_err());
+}
+
+#[test]
+fn test_gate_images_match_simulation() {
+    let gates = [
+        CliffordGate::H(1),
+        CliffordGate::X(1),
+        CliffordGate::Y(1),
+        CliffordGate::Z(1),
+        CliffordGate::S(1),
+        CliffordGate::Sdg(1),
+        CliffordGate::SqrtX(1),
+        CliffordGate::SqrtXdg(1),
+        CliffordGate::SqrtY(1),
+        CliffordGate::SqrtYdg(1),
+        CliffordGate::HXY(1),
+        CliffordGate::HYZ(1),
+    ];
+    for seed in 0..5 {
+        let base = random_circuit(3, 30, Some(seed));
+        for gate in &gates {
+            let mut expected = base.clone();
+            expected.add_gate(gate.clone());
+            let expected = StabilizerCHForm::try_from(&expected).unwrap();
+
+            let clifford = SingleQubitClifford::from_gates(std::slice::from_ref(gate)).unwrap();
+            let mut actual = base.clone();
+            actual.add_multiple_gates(clifford.to_gates(1));
+            let actual = StabilizerCHForm::try_from(&actual).unwrap();
+
+            let overlap = expected.inner_product(&actual).norm();
+            assert!((overlap - 1.0).abs() < 1e-8, "Mismatch for {:?}", gate);
+        }
+    }
+}
+
+#[test]
+fn test_apply_single_qubit_clifford() {
+    let base = random_circuit(3, 40, Some(7));
+    let state = StabilizerCHForm::try_from(&base).unwrap();
+    for clifford in SingleQubitClifford::all() {
+        let mut actual = state.clone();
+        actual.apply_single_qubit_clifford(2, clifford);
+
+        let mut circuit = base.clone();
+        circuit.add_multiple_gates(clifford.to_gates(2));
+        let expected = StabilizerCHForm::try_from(&circuit).unwrap();
+
+        assert_eq_complex_array1(&actual.to_statevector(), &expected.to_statevector());
+    }
+}
@@ -0,0 +1,68 @@
+mod common;
+use common::{assert_eq_complex, random_circuit_all_gates};
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::prelude::*;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+#[test]
+fn test_sparse_ghz_on_many_qubits() {
+    let n = 60;
+    let mut ghz = StabilizerCHForm::new(n);
+    ghz.apply_h(0);
+    for q in 1..n {
+        ghz.apply_cx(q - 1, q);
+    }
+    let sparse = ghz.to_sparse_statevector().unwrap();
+    assert_eq!(sparse.len(), 2);
+    assert!(sparse[0].0.iter().all(|&b| !b));
+    assert!(sparse[1].0.iter().all(|&b| b));
+    for (_, amplitude) in &sparse {
+        assert_eq_complex(*amplitude, Complex64::new(FRAC_1_SQRT_2, 0.0));
+    }
+}
+
+#[test]
+fn test_sparse_matches_inner_products() {
+    for seed in 0..20 {
+        let n = 2 + (seed as usize) % 4;
+        let circuit = random_circuit_all_gates(n, 60, seed);
+        let mut state = StabilizerCHForm::try_from(&circuit).unwrap();
+        state.set_global_phase(Complex64::new(0.6, 0.8));
+        let sparse = state.to_sparse_statevector().unwrap();
+        assert!(sparse.len().is_power_of_two());
+
+        let mut dense = state.to_statevector();
+        let norm: f64 = sparse.iter().map(|(_, a)| a.norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-10);
+        let mut previous = None;
+        for (bitstring, amplitude) in &sparse {
+            let index: usize = (0..n).filter(|&j| bitstring[j]).map(|j| 1 << j).sum();
+            assert!(previous < Some(index), "entries must be ordered");
+            previous = Some(index);
+
+            let mut basis = StabilizerCHForm::new(n);
+            for j in (0..n).filter(|&j| bitstring[j]) {
+                basis.apply_x(j);
+            }
+            assert_eq_complex(*amplitude, basis.inner_product(&state));
+            assert_eq_complex(dense[index], *amplitude);
+            dense[index] = Complex64::new(0.0, 0.0);
+        }
+        assert!(dense.iter().all(|a| a.norm() < 1e-10));
+    }
+}
+
+#[test]
+fn test_sparse_support_limit() {
+    let mut state = StabilizerCHForm::new(30);
+    for q in 0..25 {
+        state.apply_h(q);
+    }
+    assert!(state.to_sparse_statevector().is_err());
+
+    // Only the support counts, not the number of qubits.
+    let mut state = StabilizerCHForm::new(200);
+    state.apply_h(0);
+    state.apply_cx(0, 199);
+    assert_eq!(state.to_sparse_statevector().unwrap().len(), 2);
+}
@@ -0,0 +1,91 @@
+mod common;
+use common::random_circuit_all_gates;
+use ndarray::array;
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::prelude::*;
+
+#[test]
+fn test_unitaries_match_reference_matrices() {
+    let o = Complex64::new(0.0, 0.0);
+    let l = Complex64::new(1.0, 0.0);
+    let i = Complex64::new(0.0, 1.0);
+    let r = 1.0 / 2f64.sqrt();
+
+    // Qiskit's conventions, e.g. SqrtX = e^{iπ/4} Rx(π/2) and Y = iXZ.
+    // Two-qubit matrices use little-endian ordering (first argument = LSB).
+    let cases = vec![
+        (CliffordGate::H(0), array![[l, l], [l, -l]] * r),
+        (CliffordGate::X(0), array![[o, l], [l, o]]),
+        (CliffordGate::Y(0), array![[o, -i], [i, o]]),
+        (CliffordGate::Z(0), array![[l, o], [o, -l]]),
+        (CliffordGate::S(0), array![[l, o], [o, i]]),
+        (CliffordGate::Sdg(0), array![[l, o], [o, -i]]),
+        (
+            CliffordGate::SqrtX(0),
+            array![[l + i, l - i], [l - i, l + i]] * 0.5,
+        ),
+        (
+            CliffordGate::SqrtXdg(0),
+            array![[l - i, l + i], [l + i, l - i]] * 0.5,
+        ),
+        (
+            CliffordGate::CX(0, 1),
+            array![[l, o, o, o], [o, o, o, l], [o, o, l, o], [o, l, o, o]],
+        ),
+        (
+            CliffordGate::CZ(0, 1),
+            array![[l, o, o, o], [o, l, o, o], [o, o, l, o], [o, o, o, -l]],
+        ),
+        (
+            CliffordGate::Swap(0, 1),
+            array![[l, o, o, o], [o, o, l, o], [o, l, o, o], [o, o, o, l]],
+        ),
+    ];
+    for (gate, expected) in cases {
+        let mut circuit = CliffordCircuit::new(gate.qubits().len());
+        circuit.add_gate(gate.clone());
+        let actual = circuit.to_unitary().unwrap();
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).norm() < 1e-8, "{:?}: got {:?}", gate, actual);
+        }
+    }
+}
+
+#[test]
+fn test_unitary_is_consistent_with_statevector_and_tableau() {
+    for seed in 0..5 {
+        let circuit = random_circuit_all_gates(3, 40, seed);
+        let unitary = circuit.to_unitary().unwrap();
+
+        // U is unitary and its first column is the output statevector.
+        let product = unitary.t().mapv(|a| a.conj()).dot(&unitary);
+        for ((row, col), a) in product.indexed_iter() {
+            let expected = if row == col { 1.0 } else { 0.0 };
+            assert!((a - Complex64::new(expected, 0.0)).norm() < 1e-8);
+        }
+        let statevector = StabilizerCHForm::try_from(&circuit)
+            .unwrap()
+            .to_statevector();
+        for (a, e) in unitary.column(0).iter().zip(statevector.iter()) {
+            assert!((a - e).norm() < 1e-8);
+        }
+
+        // The tableau round trip preserves the unitary up to a global phase.
+        let tableau = circuit.to_tableau().unwrap();
+        let rebuilt = CliffordCircuit::from_tableau(&tableau);
+        assert_eq!(rebuilt.to_tableau().unwrap(), tableau);
+        let rebuilt_unitary = rebuilt.to_unitary().unwrap();
+        let overlap: Complex64 = unitary
+            .iter()
+            .zip(rebuilt_unitary.iter())
+            .map(|(a, b)| a.conj() * b)
+            .sum();
+        assert!((overlap.norm() - 8.0).abs() < 1e-8);
+    }
+
+    assert!(CliffordCircuit::new(11).to_unitary().is_err());
+    let mut invalid = CliffordCircuit::new(1);
+    invalid.apply_x(3);
+    assert!(invalid.to_tableau().is_err());
+    assert!(invalid.to_unitary().is_err());
+}